@@ -0,0 +1,144 @@
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+pub const YEAR: u16 = 2022;
+
+/// Environment variable holding the value of the `session` cookie of a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// A page returned by the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    pub body: String,
+    pub etag: Option<String>,
+}
+
+/// Talks to the Advent of Code website on behalf of the user owning `session`. Requests of all
+/// clients sharing a state directory are at least `min_interval` apart, even across runs.
+#[derive(Debug)]
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    state_dir: PathBuf,
+    min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>, state_dir: impl Into<PathBuf>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("aoc-2022/", env!("CARGO_PKG_VERSION")))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            state_dir: state_dir.into(),
+            min_interval: Duration::from_secs(5),
+            agent,
+        }
+    }
+
+    /// Minimum time between two requests.
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Requests `path`, or `None` if the server confirms that `etag` is still current.
+    pub fn get(&self, path: &str, etag: Option<&str>) -> Result<Option<Page>> {
+        let (mut request, url) = self.request("GET", path)?;
+
+        if let Some(etag) = etag {
+            request = request.set("If-None-Match", etag);
+        }
+
+        self.wait_for_turn()?;
+        let response = send(request.call(), &url)?;
+
+        if response.status() == 304 && etag.is_some() {
+            return Ok(None);
+        }
+
+        let etag = response.header("ETag").map(String::from);
+        let body = response
+            .into_string()
+            .with_context(|| format!("Could not read response of {url}"))?;
+
+        Ok(Some(Page { body, etag }))
+    }
+
+    /// Posts `form` to `path` and returns the body of the response.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let (request, url) = self.request("POST", path)?;
+
+        self.wait_for_turn()?;
+        let response = send(request.send_form(form), &url)?;
+
+        response
+            .into_string()
+            .with_context(|| format!("Could not read response of {url}"))
+    }
+
+    fn request(&self, method: &str, path: &str) -> Result<(ureq::Request, String)> {
+        let session = self.session.as_deref().ok_or_else(|| {
+            anyhow!("Set {SESSION_VAR} to the session cookie of adventofcode.com to use the site")
+        })?;
+
+        let url = format!("{}{path}", self.base_url);
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={session}"));
+
+        Ok((request, url))
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request and records this one.
+    fn wait_for_turn(&self) -> Result<()> {
+        // time of the last request in milliseconds since the Unix epoch
+        let path = self.state_dir.join("last-request");
+
+        if let Ok(last) = fs::read_to_string(&path) {
+            let last = Duration::from_millis(last.trim().parse().unwrap_or_default());
+            let elapsed = now().saturating_sub(last);
+
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        fs::create_dir_all(&self.state_dir)
+            .with_context(|| format!("Could not create directory {}", self.state_dir.display()))?;
+
+        fs::write(&path, now().as_millis().to_string())
+            .with_context(|| format!("Could not write file {}", path.display()))
+    }
+}
+
+fn send(result: Result<ureq::Response, ureq::Error>, url: &str) -> Result<ureq::Response> {
+    match result {
+        Ok(response) => Ok(response),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            bail!("{url} answered {status}: {}", body.trim());
+        }
+        Err(err) => Err(err).with_context(|| format!("Could not request {url}")),
+    }
+}
+
+/// Time since the Unix epoch.
+pub(crate) fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
@@ -0,0 +1,27 @@
+use utils::Day;
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day_1::Elf>(),
+        Day::new::<day_2::Round>(),
+        Day::new::<day_3::Rucksack>(),
+        Day::new::<day_4::Task>(),
+        Day::new::<day_5::Stacks>(),
+        Day::new::<day_6::Signal>(),
+        Day::new::<day_7::Filesystem>(),
+        Day::new::<day_8::Tree>(),
+        Day::new::<day_9::Rope>(),
+        Day::new::<day_10::Cpu>(),
+        Day::new::<day_11::Game>(),
+        Day::new::<day_12::Node>(),
+        Day::new::<day_13::Packet>(),
+        Day::new::<day_14::Cave>(),
+        Day::new::<day_15::Grid>(),
+        Day::new::<day_16::Valve>(),
+    ]
+}
+
+/// The day with this number, if it is solved.
+pub fn get(number: u8) -> Option<Day> {
+    all().into_iter().find(|day| day.number == number)
+}
@@ -0,0 +1,174 @@
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{ensure, Context, Result};
+
+use crate::client::{Client, YEAR};
+
+/// Something that can be downloaded for a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Input,
+    /// The puzzle page, which gains the second part once the first one is solved.
+    Description,
+}
+
+impl Resource {
+    fn url_path(self, day: u8) -> String {
+        match self {
+            Resource::Input => format!("/{YEAR}/day/{day}/input"),
+            Resource::Description => format!("/{YEAR}/day/{day}"),
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            Resource::Input => "input.txt",
+            Resource::Description => "puzzle.html",
+        }
+    }
+}
+
+/// Downloaded files, stored as `day_N/input.txt` and `day_N/puzzle.html` below a directory,
+/// each next to a `.etag` file if the server sent one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8, resource: Resource) -> PathBuf {
+        self.dir
+            .join(format!("day_{day}"))
+            .join(resource.file_name())
+    }
+
+    fn etag_path(&self, day: u8, resource: Resource) -> PathBuf {
+        let mut path = self.path(day, resource).into_os_string();
+        path.push(".etag");
+
+        path.into()
+    }
+
+    pub fn read(&self, day: u8, resource: Resource) -> Result<Option<String>> {
+        read_optional(&self.path(day, resource))
+    }
+
+    pub fn etag(&self, day: u8, resource: Resource) -> Result<Option<String>> {
+        read_optional(&self.etag_path(day, resource))
+    }
+
+    fn store(&self, day: u8, resource: Resource, body: &str, etag: Option<&str>) -> Result<()> {
+        let path = self.path(day, resource);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Could not create directory {}", dir.display()))?;
+        }
+
+        write(&path, body)?;
+
+        let etag_path = self.etag_path(day, resource);
+        match etag {
+            Some(etag) => write(&etag_path, etag),
+            None => match fs::remove_file(&etag_path) {
+                Err(err) if err.kind() != ErrorKind::NotFound => Err(err)
+                    .with_context(|| format!("Could not remove file {}", etag_path.display())),
+                _ => Ok(()),
+            },
+        }
+    }
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("Could not read file {}", path.display())),
+    }
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content).with_context(|| format!("Could not write file {}", path.display()))
+}
+
+/// How a fetched file was obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Taken from the cache without asking the server.
+    Cached,
+    /// The server confirmed that the cached file is still current.
+    NotModified,
+    Downloaded,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetched {
+    pub path: PathBuf,
+    pub outcome: Outcome,
+}
+
+/// Downloads inputs and puzzle descriptions into a [`Cache`]. Inputs never change, so they are
+/// only requested once. Descriptions are revalidated on every fetch, which the server answers
+/// without a body if the etag still matches.
+#[derive(Debug)]
+pub struct Fetcher {
+    client: Client,
+    cache: Cache,
+}
+
+impl Fetcher {
+    pub fn new(client: Client, cache: Cache) -> Self {
+        Self { client, cache }
+    }
+
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// Makes sure `resource` of `day` is in the cache. With `refresh`, a cached input is
+    /// revalidated as well.
+    pub fn fetch(&self, day: u8, resource: Resource, refresh: bool) -> Result<Fetched> {
+        ensure!((1..=25).contains(&day), "There is no day {day}");
+
+        let path = self.cache.path(day, resource);
+        let cached = self.cache.read(day, resource)?.is_some();
+
+        if cached && resource == Resource::Input && !refresh {
+            return Ok(Fetched {
+                path,
+                outcome: Outcome::Cached,
+            });
+        }
+
+        let etag = if cached {
+            self.cache.etag(day, resource)?
+        } else {
+            None
+        };
+
+        let outcome = match self
+            .client
+            .get(&resource.url_path(day), etag.as_deref().map(str::trim))?
+        {
+            Some(page) => {
+                self.cache
+                    .store(day, resource, &page.body, page.etag.as_deref())?;
+                Outcome::Downloaded
+            }
+            None => Outcome::NotModified,
+        };
+
+        Ok(Fetched { path, outcome })
+    }
+}
@@ -0,0 +1,7 @@
+pub mod client;
+pub mod days;
+pub mod fetch;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+pub mod scaffold;
+pub mod submit;
@@ -0,0 +1,714 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::{
+    client::{Client, DEFAULT_BASE_URL, SESSION_VAR},
+    fetch::{Cache, Fetcher, Outcome, Resource},
+    scaffold,
+    submit::{SubmissionLog, Submitter, Verdict},
+};
+use clap::{Args, Parser, Subcommand};
+use utils::{
+    day_input_path, workspace_root, Answers, Day, History, Manifest, Measured, Overrides,
+    ParamArgs, RawParams, Recorder, Run,
+};
+
+mod table;
+
+use table::Table;
+
+#[derive(Debug, Parser)]
+#[command(about = "Runs Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a single day, or every day in order
+    Run {
+        /// Day number, or `all`
+        day: Selection,
+
+        /// Directory containing the `day_N/input.txt` files
+        #[arg(long, default_value_os_t = workspace_root())]
+        inputs: PathBuf,
+
+        /// Solve the input of this name declared in `answers.toml`, like `example`, with its
+        /// parameters
+        #[arg(long, value_name = "NAME", conflicts_with = "inputs")]
+        named: Option<String>,
+
+        #[command(flatten)]
+        params: ParamArgs,
+
+        /// Solve every day this many times and print timing statistics instead of the answers
+        #[arg(long, value_name = "RUNS")]
+        bench: Option<usize>,
+
+        /// Also write the benchmark results as JSON to this file
+        #[arg(long, value_name = "FILE", requires = "bench")]
+        bench_output: Option<PathBuf>,
+
+        /// Name to store the benchmark run under in the history, e.g. a commit hash
+        #[arg(long, requires = "bench")]
+        label: Option<String>,
+
+        /// Benchmark history the results are appended to
+        #[arg(long, value_name = "FILE", default_value_os_t = default_history())]
+        history: PathBuf,
+    },
+    /// Replay how a day finds its answers, in the terminal or into a file
+    Animate {
+        /// Day number
+        day: u8,
+
+        /// Directory containing the `day_N/input.txt` files
+        #[arg(long, default_value_os_t = workspace_root())]
+        inputs: PathBuf,
+
+        /// Animate the input of this name declared in `answers.toml`, like `example`, with its
+        /// parameters
+        #[arg(long, value_name = "NAME", conflicts_with = "inputs")]
+        named: Option<String>,
+
+        #[command(flatten)]
+        params: ParamArgs,
+
+        /// Write the animation to this file instead of playing it: an animated `.gif`, a `.png`
+        /// of the last frame or every frame as `.txt`
+        #[arg(long, short, value_name = "FILE")]
+        output: Option<PathBuf>,
+
+        /// Keep only every Nth frame
+        #[arg(long, value_name = "N", default_value_t = 1)]
+        every: usize,
+
+        /// Most frames to keep, longer animations are thinned out evenly
+        #[arg(long, value_name = "N", default_value_t = 500)]
+        max_frames: usize,
+
+        /// Time between frames, in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = 50)]
+        delay: u64,
+
+        /// Size of a cell in images, in pixels
+        #[arg(long, value_name = "PIXELS", default_value_t = 4)]
+        scale: usize,
+    },
+    /// Compare the latest benchmark run in the history against a baseline run
+    Compare {
+        /// Label or number of the baseline run. Defaults to the run before the latest one
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Slowdown of the median, in percent, above which a stage counts as regressed
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Benchmark history to read the runs from
+        #[arg(long, value_name = "FILE", default_value_os_t = default_history())]
+        history: PathBuf,
+    },
+    /// Download the input and puzzle description of a day, using the session cookie in
+    /// `AOC_SESSION`
+    Fetch {
+        /// Day number, 1 to 25
+        day: u8,
+
+        /// Directory containing the `day_N/input.txt` files. A missing input is copied there
+        #[arg(long, default_value_os_t = workspace_root())]
+        inputs: PathBuf,
+
+        /// Ask the server for a newer input even if one is cached
+        #[arg(long)]
+        refresh: bool,
+
+        #[command(flatten)]
+        site: SiteOptions,
+    },
+    /// Submit the answer to one part of a day, using the session cookie in `AOC_SESSION`.
+    /// Answers the submission log already knows to be wrong are refused
+    Submit {
+        /// Day number, 1 to 25
+        day: u8,
+
+        /// Part to submit, 1 or 2
+        part: u8,
+
+        /// The answer. Defaults to solving the day's input
+        answer: Option<String>,
+
+        /// Directory containing the `day_N/input.txt` files
+        #[arg(long, default_value_os_t = workspace_root())]
+        inputs: PathBuf,
+
+        /// Log of all submitted answers. Defaults to `submissions.jsonl` in the cache directory
+        #[arg(long, value_name = "FILE")]
+        log: Option<PathBuf>,
+
+        #[command(flatten)]
+        site: SiteOptions,
+    },
+    /// Create the crate of a new day and register it in the workspace, the runner and the
+    /// answers manifest
+    NewDay {
+        /// Day number, 1 to 25
+        day: u8,
+
+        /// Name of the type implementing `Solver`
+        #[arg(long, default_value = "Puzzle")]
+        name: String,
+
+        /// Root of the workspace to add the day to
+        #[arg(long, value_name = "DIR", default_value_os_t = workspace_root())]
+        root: PathBuf,
+    },
+}
+
+/// How to reach the Advent of Code website.
+#[derive(Debug, Args)]
+struct SiteOptions {
+    /// Directory for downloads and other state of the site
+    #[arg(long, value_name = "DIR", default_value_os_t = default_cache())]
+    cache: PathBuf,
+
+    /// Site to talk to
+    #[arg(long, value_name = "URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Minimum time between two requests, in seconds
+    #[arg(long, value_name = "SECONDS", default_value_t = 5.0)]
+    min_interval: f64,
+}
+
+impl SiteOptions {
+    fn client(&self) -> Result<Client> {
+        let min_interval = Duration::try_from_secs_f64(self.min_interval)
+            .map_err(|_| anyhow!("Invalid minimum interval: {}", self.min_interval))?;
+
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.is_empty());
+
+        Ok(Client::new(&self.base_url, session, &self.cache).min_interval(min_interval))
+    }
+}
+
+fn default_history() -> PathBuf {
+    workspace_root().join("bench-history.jsonl")
+}
+
+fn default_cache() -> PathBuf {
+    workspace_root().join(".cache")
+}
+
+/// Where the runner reads the input of each day from, and the parameters to solve it with.
+struct Inputs {
+    source: Source,
+    overrides: Overrides,
+}
+
+enum Source {
+    /// `day_N/input.txt` below a directory, solved with the default parameters.
+    Dir(PathBuf),
+    /// The input of this name in the manifest, with its parameters.
+    Named(Manifest, String),
+}
+
+impl Inputs {
+    fn new(dir: PathBuf, named: Option<String>, params: &ParamArgs) -> Result<Self> {
+        let source = match named {
+            Some(name) => {
+                let manifest = Manifest::load(&workspace_root().join("answers.toml"))?;
+
+                Source::Named(manifest, name)
+            }
+            None => Source::Dir(dir),
+        };
+
+        Ok(Self {
+            source,
+            overrides: params.overrides()?,
+        })
+    }
+
+    /// Like [`Inputs::new`], for solving the days of `selection`.
+    fn for_selection(
+        selection: Selection,
+        dir: PathBuf,
+        named: Option<String>,
+        params: &ParamArgs,
+    ) -> Result<Self> {
+        let inputs = Self::new(dir, named, params)?;
+        if selection == Selection::All {
+            inputs.overrides.ensure_scoped()?;
+        }
+
+        Ok(inputs)
+    }
+
+    /// The real inputs below `dir`, without any overrides.
+    fn dir(dir: &Path) -> Self {
+        Self {
+            source: Source::Dir(dir.to_path_buf()),
+            overrides: Overrides::default(),
+        }
+    }
+
+    fn load(&self, day: u8) -> Result<(String, RawParams)> {
+        let (input, params) = match &self.source {
+            Source::Dir(dir) => {
+                let input = utils::read_file(&day_input_path(dir, day))?;
+
+                (input, RawParams::default())
+            }
+            Source::Named(manifest, name) => {
+                let input = manifest.input(day, name)?;
+
+                (input.read()?, input.params)
+            }
+        };
+
+        Ok((input, self.overrides.apply(day, params)))
+    }
+
+    /// Name of the input of `day`, to tell benchmark results of different inputs apart.
+    fn name(&self, day: u8) -> String {
+        match &self.source {
+            Source::Dir(dir) if *dir == workspace_root() => "real".to_string(),
+            Source::Dir(dir) => day_input_path(dir, day).display().to_string(),
+            Source::Named(_, name) => name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            day => {
+                let day = day
+                    .parse()
+                    .map_err(|_| anyhow!("Expected a day number or `all`, got: {day}"))?;
+
+                Ok(Self::Day(day))
+            }
+        }
+    }
+}
+
+impl Selection {
+    fn days(self) -> Result<Vec<Day>> {
+        match self {
+            Selection::All => Ok(aoc::days::all()),
+            Selection::Day(number) => aoc::days::get(number)
+                .map(|day| vec![day])
+                .ok_or_else(|| anyhow!("Day {number} is not solved yet")),
+        }
+    }
+}
+
+fn solve(day: &Day, inputs: &Inputs) -> Result<Answers> {
+    let (input, params) = inputs.load(day.number)?;
+
+    day.solve(&input, &params)
+}
+
+fn run(selection: Selection, inputs: &Inputs) -> Result<()> {
+    let mut table = Table::new(["Day", "Part 1", "Part 2", "Parse", "Time 1", "Time 2"]);
+    let mut failed = 0;
+
+    for day in selection.days()? {
+        let row = match solve(&day, inputs) {
+            Ok(Answers {
+                part1,
+                part2,
+                timings,
+            }) => {
+                if part1.is_err() || part2.is_err() {
+                    failed += 1;
+                }
+
+                [
+                    day.number.to_string(),
+                    format_answer(part1),
+                    format_answer(part2),
+                    format!("{:.2?}", timings.parse),
+                    format!("{:.2?}", timings.part1),
+                    format!("{:.2?}", timings.part2),
+                ]
+            }
+            Err(err) => {
+                failed += 1;
+
+                [
+                    day.number.to_string(),
+                    format!("error: {err:#}"),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ]
+            }
+        };
+
+        table.push(row);
+    }
+
+    print!("{table}");
+
+    if failed > 0 {
+        bail!("{failed} day(s) could not be solved");
+    }
+
+    Ok(())
+}
+
+struct BenchOptions {
+    runs: usize,
+    output: Option<PathBuf>,
+    label: Option<String>,
+    history: History,
+}
+
+fn bench(selection: Selection, inputs: &Inputs, options: BenchOptions) -> Result<()> {
+    let BenchOptions {
+        runs,
+        output,
+        label,
+        history,
+    } = options;
+
+    let mut table = Table::new(["Day", "Stage", "Min", "Median", "P95", "Max"]);
+    let mut results = Vec::new();
+
+    for day in selection.days()? {
+        let bench = inputs.load(day.number).and_then(|(input, params)| {
+            let bench = day.bench(&input, &params, runs)?;
+
+            Ok(Measured {
+                input: inputs.name(day.number),
+                params,
+                bench,
+            })
+        });
+
+        match bench {
+            Ok(measured) => {
+                let bench = &measured.bench;
+                for (stage, stats) in bench.stages() {
+                    table.push([
+                        day.number.to_string(),
+                        stage.to_string(),
+                        format!("{:.2?}", stats.min()),
+                        format!("{:.2?}", stats.median()),
+                        format!("{:.2?}", stats.p95()),
+                        format!("{:.2?}", stats.max()),
+                    ]);
+                }
+
+                results.push(measured);
+            }
+            Err(err) => table.push([
+                day.number.to_string(),
+                format!("error: {err:#}"),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ]),
+        }
+    }
+
+    print!("{table}");
+
+    if let Some(path) = output {
+        let benches: Vec<_> = results.iter().map(|measured| measured.bench).collect();
+        utils::write_benches(&path, &benches)?;
+    }
+
+    if !results.is_empty() {
+        let run = history.record(&results, label.as_deref())?;
+        println!("Recorded run {run} in {}", history.path().display());
+    }
+
+    Ok(())
+}
+
+fn compare(history: &History, baseline: Option<&str>, threshold: f64) -> Result<()> {
+    let mut runs = history.runs()?;
+    let latest = runs
+        .pop()
+        .ok_or_else(|| anyhow!("No benchmark runs in {}", history.path().display()))?;
+
+    let baseline = match baseline {
+        Some(selector) => runs
+            .iter()
+            .rev()
+            .find(|run| run.matches(selector))
+            .ok_or_else(|| anyhow!("No benchmark run named {selector} before the latest one"))?,
+        None => runs
+            .last()
+            .ok_or_else(|| anyhow!("Need at least two benchmark runs to compare"))?,
+    };
+
+    let mut table = Table::new(["Day", "Stage", "Baseline", "Latest", "Change", "Status"]);
+    let comparisons = utils::compare(baseline, &latest, threshold);
+
+    for comparison in &comparisons {
+        table.push([
+            comparison.day.to_string(),
+            comparison.stage.to_string(),
+            format!("{:.2?}", comparison.baseline),
+            format!("{:.2?}", comparison.latest),
+            format!("{:+.1}%", comparison.change),
+            if comparison.regression {
+                "regression".to_string()
+            } else {
+                String::new()
+            },
+        ]);
+    }
+
+    println!(
+        "Comparing run {} against baseline {}",
+        run_name(&latest),
+        run_name(baseline)
+    );
+    print!("{table}");
+
+    let regressions = comparisons.iter().filter(|c| c.regression).count();
+    if regressions > 0 {
+        bail!("{regressions} stage(s) got more than {threshold}% slower");
+    }
+
+    Ok(())
+}
+
+fn run_name(run: &Run) -> String {
+    match &run.label {
+        Some(label) => format!("{} ({label})", run.run),
+        None => run.run.to_string(),
+    }
+}
+
+fn fetch(fetcher: &Fetcher, day: u8, inputs: &Path, refresh: bool) -> Result<()> {
+    for resource in [Resource::Input, Resource::Description] {
+        let fetched = fetcher.fetch(day, resource, refresh)?;
+        let outcome = match fetched.outcome {
+            Outcome::Cached => "cached",
+            Outcome::NotModified => "not modified",
+            Outcome::Downloaded => "downloaded",
+        };
+
+        println!("{} ({outcome})", fetched.path.display());
+    }
+
+    let target = day_input_path(inputs, day);
+    if !target.exists() {
+        let input = fetcher.cache().path(day, Resource::Input);
+
+        if let Some(dir) = target.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Could not create directory {}", dir.display()))?;
+        }
+
+        std::fs::copy(&input, &target)
+            .with_context(|| format!("Could not copy input to {}", target.display()))?;
+
+        println!("Copied input to {}", target.display());
+    }
+
+    Ok(())
+}
+
+struct AnimateOptions {
+    output: Option<PathBuf>,
+    every: usize,
+    max_frames: usize,
+    delay: Duration,
+    scale: usize,
+}
+
+fn animate(day: u8, inputs: &Inputs, options: AnimateOptions) -> Result<()> {
+    let day = aoc::days::get(day).ok_or_else(|| anyhow!("Day {day} is not solved yet"))?;
+
+    let (input, params) = inputs.load(day.number)?;
+
+    let mut recorder = Recorder::new(options.every, options.max_frames);
+    day.animate(&input, &params, &mut recorder)?;
+    let animation = recorder.into_animation(options.delay);
+
+    match options.output {
+        Some(path) => {
+            animation.save(&path, options.scale)?;
+            println!(
+                "Wrote {} frames to {}",
+                animation.frames.len(),
+                path.display()
+            );
+
+            Ok(())
+        }
+        None => animation.play(&mut std::io::stdout().lock()),
+    }
+}
+
+fn submit(
+    submitter: &Submitter,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    inputs: &Path,
+) -> Result<()> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let [day] = &Selection::Day(day).days()?[..] else {
+                bail!("Day {day} is registered more than once");
+            };
+
+            let answers = solve(day, &Inputs::dir(inputs))?;
+            let answer = match part {
+                1 => answers.part1,
+                2 => answers.part2,
+                _ => bail!("There is no part {part}"),
+            };
+
+            answer.with_context(|| format!("Could not solve part {part}"))?
+        }
+    };
+
+    println!("Submitting {answer} for day {day} part {part}");
+
+    let verdict = submitter.submit(day, part, &answer)?;
+    println!("Recorded in {}", submitter.log().path().display());
+
+    if verdict != Verdict::Correct {
+        bail!("The answer was not accepted: {verdict}");
+    }
+
+    println!("That's the right answer!");
+
+    Ok(())
+}
+
+fn new_day(root: &Path, day: u8, name: &str) -> Result<()> {
+    for path in scaffold::new_day(root, day, name)? {
+        println!("{}", path.display());
+    }
+
+    println!("Created day_{day}, fetch its input with `aoc fetch {day}`");
+
+    Ok(())
+}
+
+fn format_answer(answer: Result<String>) -> String {
+    match answer {
+        Ok(answer) => answer,
+        Err(err) => format!("error: {err:#}"),
+    }
+}
+
+fn main() -> Result<()> {
+    utils::init_tracing();
+
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            inputs,
+            named,
+            params,
+            bench: Some(runs),
+            bench_output,
+            label,
+            history,
+        } => {
+            let options = BenchOptions {
+                runs,
+                output: bench_output,
+                label,
+                history: History::new(history),
+            };
+
+            bench(
+                day,
+                &Inputs::for_selection(day, inputs, named, &params)?,
+                options,
+            )
+        }
+        Command::Run {
+            day,
+            inputs,
+            named,
+            params,
+            ..
+        } => run(day, &Inputs::for_selection(day, inputs, named, &params)?),
+        Command::Animate {
+            day,
+            inputs,
+            named,
+            params,
+            output,
+            every,
+            max_frames,
+            delay,
+            scale,
+        } => {
+            let options = AnimateOptions {
+                output,
+                every,
+                max_frames,
+                delay: Duration::from_millis(delay),
+                scale,
+            };
+
+            animate(day, &Inputs::new(inputs, named, &params)?, options)
+        }
+        Command::Compare {
+            baseline,
+            threshold,
+            history,
+        } => compare(&History::new(history), baseline.as_deref(), threshold),
+        Command::Fetch {
+            day,
+            inputs,
+            refresh,
+            site,
+        } => {
+            let fetcher = Fetcher::new(site.client()?, Cache::new(&site.cache));
+
+            fetch(&fetcher, day, &inputs, refresh)
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            inputs,
+            log,
+            site,
+        } => {
+            let log = log.unwrap_or_else(|| site.cache.join("submissions.jsonl"));
+            let submitter = Submitter::new(site.client()?, SubmissionLog::new(log));
+
+            submit(&submitter, day, part, answer, &inputs)
+        }
+        Command::NewDay { day, name, root } => new_day(&root, day, &name),
+    }
+}
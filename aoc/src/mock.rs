@@ -0,0 +1,338 @@
+//! Stand-in for the Advent of Code website, so downloading can be exercised without network
+//! access or a real session.
+
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+
+/// How long a client may take to send its request before the server moves on to the next one.
+const READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A request as the server received it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    /// Header names are lowercase.
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    pages: BTreeMap<String, String>,
+    // correct answers of the parts that are not solved yet
+    answers: BTreeMap<(u8, u8), String>,
+    cooldown: Duration,
+    blocked_until: Option<Instant>,
+    requests: Vec<Request>,
+}
+
+/// Serves fixed pages over plain HTTP on a free port of localhost until dropped. Like the real
+/// site it answers `400` without a `session` cookie, `404` for unknown paths and `304` when
+/// `If-None-Match` carries the current etag of a page. Answers posted to
+/// `/2022/day/N/answer` are judged against the ones set with [`MockServer::set_answer`], with
+/// the same wording as the real site.
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    pub fn start() -> Result<Self> {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").context("Could not bind the mock server")?;
+        let addr = listener.local_addr()?;
+
+        let state = Arc::new(Mutex::new(State::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread = thread::spawn({
+            let state = Arc::clone(&state);
+            let stop = Arc::clone(&stop);
+
+            move || {
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    if let Ok(stream) = stream {
+                        // a broken or stalled connection only affects the client that made it
+                        let _ = stream
+                            .set_read_timeout(Some(READ_TIMEOUT))
+                            .map_err(anyhow::Error::from)
+                            .and_then(|()| handle(stream, &state));
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            addr,
+            state,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Base URL to pass to the [`Fetcher`](crate::fetch::Fetcher).
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Serves `body` at `path` from now on, replacing what was there before.
+    pub fn set_page(&self, path: &str, body: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.pages.insert(path.to_string(), body.to_string());
+    }
+
+    /// Accepts `answer` for `part` of `day` until it is submitted.
+    pub fn set_answer(&self, day: u8, part: u8, answer: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.answers.insert((day, part), answer.to_string());
+    }
+
+    /// Time a wrong answer blocks further submissions, none by default.
+    pub fn set_cooldown(&self, cooldown: Duration) {
+        self.state.lock().unwrap().cooldown = cooldown;
+    }
+
+    /// All requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+
+        // wake up the accept loop so it notices
+        let _ = TcpStream::connect(self.addr);
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn handle(mut stream: TcpStream, state: &Mutex<State>) -> Result<()> {
+    let mut reader = BufReader::new(&stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = BTreeMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers
+        .get("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    let request = Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        let response = respond(&request, &mut state);
+        state.requests.push(request);
+
+        response
+    };
+
+    stream.write_all(response.as_bytes())?;
+    stream.flush()?;
+
+    Ok(())
+}
+
+fn respond(request: &Request, state: &mut State) -> String {
+    let has_session = request
+        .header("cookie")
+        .is_some_and(|cookie| cookie.split(';').any(|c| c.trim().starts_with("session=")));
+
+    if !has_session {
+        return response(
+            "400 Bad Request",
+            &[],
+            "Please log in to get your puzzle input.\n",
+        );
+    }
+
+    if request.method == "POST" {
+        return match answer_day(&request.path) {
+            Some(day) => article(&judge(request, day, state)),
+            None => response("404 Not Found", &[], "404 Not Found\n"),
+        };
+    }
+
+    let Some(body) = state.pages.get(&request.path) else {
+        return response("404 Not Found", &[], "404 Not Found\n");
+    };
+
+    let etag = etag(body);
+    if request.header("if-none-match") == Some(etag.as_str()) {
+        return response("304 Not Modified", &[("ETag", &etag)], "");
+    }
+
+    response("200 OK", &[("ETag", &etag)], body)
+}
+
+/// Day of an answer path like `/2022/day/1/answer`.
+fn answer_day(path: &str) -> Option<u8> {
+    path.strip_prefix("/2022/day/")?
+        .strip_suffix("/answer")?
+        .parse()
+        .ok()
+}
+
+fn judge(request: &Request, day: u8, state: &mut State) -> String {
+    let form = form_values(&request.body);
+    let level: u8 = form
+        .get("level")
+        .and_then(|level| level.parse().ok())
+        .unwrap_or_default();
+    let answer = form.get("answer").map(String::as_str).unwrap_or_default();
+
+    if let Some(until) = state.blocked_until {
+        let now = Instant::now();
+        if until > now {
+            let left = (until - now).as_secs_f64().ceil() as u64;
+            let left = match (left / 60, left % 60) {
+                (0, seconds) => format!("{seconds}s"),
+                (minutes, seconds) => format!("{minutes}m {seconds}s"),
+            };
+
+            return format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {left} left to wait."
+            );
+        }
+    }
+
+    let Some(expected) = state.answers.get(&(day, level)) else {
+        return "You don't seem to be solving the right level.  Did you already complete it?"
+            .to_string();
+    };
+
+    if answer == expected {
+        state.answers.remove(&(day, level));
+        return "That's the right answer!  You are one gold star closer.".to_string();
+    }
+
+    state.blocked_until = Some(Instant::now() + state.cooldown);
+
+    let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "  your answer is too high.",
+        (Ok(answer), Ok(expected)) if answer < expected => "  your answer is too low.",
+        _ => "",
+    };
+
+    format!("That's not the right answer;{hint}  Please wait before trying again.")
+}
+
+/// Decodes an `application/x-www-form-urlencoded` body.
+fn form_values(body: &str) -> BTreeMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (decode(key), decode(value)))
+        .collect()
+}
+
+fn decode(value: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut chars = value.bytes();
+
+    while let Some(byte) = chars.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = chars.by_ref().take(2).collect();
+                let decoded = std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+                match decoded {
+                    Some(byte) => bytes.push(byte),
+                    None => {
+                        bytes.push(b'%');
+                        bytes.extend(hex);
+                    }
+                }
+            }
+            byte => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn article(message: &str) -> String {
+    let body = format!("<main>\n<article><p>{message}</p></article>\n</main>\n");
+
+    response("200 OK", &[], &body)
+}
+
+fn etag(body: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+fn response(status: &str, headers: &[(&str, &str)], body: &str) -> String {
+    let mut response = format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        body.len()
+    );
+
+    for (name, value) in headers {
+        response += &format!("{name}: {value}\r\n");
+    }
+
+    response + "\r\n" + body
+}
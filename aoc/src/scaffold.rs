@@ -0,0 +1,225 @@
+//! Creates the crate of a new day from the templates in `aoc/templates` and registers it in
+//! every place that lists the days.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use utils::REAL_INPUT;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/lib.rs.template");
+const MAIN_RS: &str = include_str!("../templates/main.rs.template");
+
+/// Creates `day_N` in the workspace at `root`, with `name` as the type implementing
+/// [`Solver`](utils::Solver), and adds it to the workspace members, the runner and the answers
+/// manifest. Returns the files that were created or changed.
+///
+/// Nothing is written unless every file could be updated, and if writing one of them fails, the
+/// ones written before it are restored.
+pub fn new_day(root: &Path, day: u8, name: &str) -> Result<Vec<PathBuf>> {
+    ensure!((1..=25).contains(&day), "There is no day {day}");
+    ensure!(
+        is_type_name(name),
+        "{name} is not a valid type name, expected something like `Puzzle`"
+    );
+
+    let krate = format!("day_{day}");
+    let dir = root.join(&krate);
+    ensure!(!dir.exists(), "{} already exists", dir.display());
+
+    let render = |template: &str| {
+        template
+            .replace("{{day}}", &day.to_string())
+            .replace("{{name}}", name)
+    };
+
+    // new files have no original contents to restore
+    let mut files: Vec<(PathBuf, String, Option<String>)> = vec![
+        (dir.join("Cargo.toml"), render(CARGO_TOML), None),
+        (dir.join("src").join("lib.rs"), render(LIB_RS), None),
+        (dir.join("src").join("main.rs"), render(MAIN_RS), None),
+        (dir.join("input.txt"), String::new(), None),
+        (dir.join("example.txt"), String::new(), None),
+    ];
+
+    let workspace = root.join("Cargo.toml");
+    let original = read(&workspace)?;
+    let contents = add_member(&original, &krate)
+        .with_context(|| format!("Could not update {}", workspace.display()))?;
+    files.push((workspace, contents, Some(original)));
+
+    let runner = root.join("aoc").join("Cargo.toml");
+    let original = read(&runner)?;
+    let contents = add_dependency(&original, &krate)
+        .with_context(|| format!("Could not update {}", runner.display()))?;
+    files.push((runner, contents, Some(original)));
+
+    let days = root.join("aoc").join("src").join("days.rs");
+    let original = read(&days)?;
+    let contents = register_day(&original, &krate, name)
+        .with_context(|| format!("Could not update {}", days.display()))?;
+    files.push((days, contents, Some(original)));
+
+    let answers = root.join("answers.toml");
+    let original = read(&answers)?;
+    let contents = add_answers(&original, day);
+    files.push((answers, contents, Some(original)));
+
+    for (written, (path, contents, _)) in files.iter().enumerate() {
+        if let Err(err) = write(path, contents) {
+            rollback(&dir, &files[..written]);
+            return Err(err);
+        }
+    }
+
+    Ok(files.into_iter().map(|(path, ..)| path).collect())
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Could not create directory {}", dir.display()))?;
+    }
+
+    fs::write(path, contents).with_context(|| format!("Could not write file {}", path.display()))
+}
+
+/// Restores the original contents of the `written` files and removes the new crate at `dir`. This
+/// is a best effort, as it only runs when writing already failed.
+fn rollback(dir: &Path, written: &[(PathBuf, String, Option<String>)]) {
+    for (path, _, original) in written.iter().rev() {
+        if let Some(original) = original {
+            let _ = fs::write(path, original);
+        }
+    }
+
+    let _ = fs::remove_dir_all(dir);
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Could not read file {}", path.display()))
+}
+
+fn is_type_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars.next().is_some_and(|ch| ch.is_ascii_uppercase())
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+/// Adds `krate` at the end of the `members` list of the workspace manifest.
+fn add_member(manifest: &str, krate: &str) -> Result<String> {
+    let Some(start) = manifest.find("members = [") else {
+        bail!("There is no members list");
+    };
+    let Some(end) = manifest[start..].find("\n]").map(|end| start + end + 1) else {
+        bail!("The members list is not closed on a line of its own");
+    };
+
+    let entry = format!("\"{krate}\"");
+    ensure!(
+        !manifest[start..end].contains(&entry),
+        "{krate} is already a member"
+    );
+
+    Ok(format!(
+        "{}    {entry},\n{}",
+        &manifest[..end],
+        &manifest[end..]
+    ))
+}
+
+/// Adds a path dependency on `krate` after the last day the runner depends on.
+fn add_dependency(manifest: &str, krate: &str) -> Result<String> {
+    ensure!(
+        !manifest
+            .lines()
+            .any(|line| line.starts_with(&format!("{krate} "))),
+        "{krate} is already a dependency"
+    );
+
+    let Some(after) = insert_after(manifest, |line| {
+        line.starts_with("day_") && line.contains("path = ")
+    })
+    .or_else(|| insert_after(manifest, |line| line == "[dependencies]")) else {
+        bail!("There is no [dependencies] table");
+    };
+
+    Ok(format!(
+        "{}{krate} = {{ path = \"../{krate}\" }}\n{}",
+        &manifest[..after],
+        &manifest[after..]
+    ))
+}
+
+/// Adds `krate::name` after the last day in the list of all days.
+fn register_day(source: &str, krate: &str, name: &str) -> Result<String> {
+    ensure!(
+        !source.contains(&format!("<{krate}::")),
+        "{krate} is already registered"
+    );
+
+    let Some(after) = insert_after(source, |line| line.trim().starts_with("Day::new::<")) else {
+        bail!("There are no registered days to add to");
+    };
+
+    Ok(format!(
+        "{}        Day::new::<{krate}::{name}>(),\n{}",
+        &source[..after],
+        &source[after..]
+    ))
+}
+
+/// Appends commented out answers for both parts of the real input and the example, to be filled
+/// in once they are solved, and declares the example input.
+fn add_answers(manifest: &str, day: u8) -> String {
+    let mut manifest = manifest.to_string();
+
+    if !manifest.ends_with('\n') {
+        manifest.push('\n');
+    }
+
+    let answers = |manifest: &mut String, input: &str| {
+        for part in 1..=2 {
+            *manifest += &format!(
+                "\n# [[answer]]\n\
+                 # day = {day}\n\
+                 # part = {part}\n\
+                 # input = \"{input}\"\n\
+                 # expected = \"\"\n"
+            );
+        }
+    };
+
+    answers(&mut manifest, REAL_INPUT);
+
+    manifest += &format!(
+        "\n[[input]]\n\
+         day = {day}\n\
+         name = \"example\"\n\
+         path = \"day_{day}/example.txt\"\n"
+    );
+
+    answers(&mut manifest, "example");
+
+    manifest
+}
+
+/// Byte offset just past the last line of `text` matching `predicate`.
+fn insert_after(text: &str, predicate: impl Fn(&str) -> bool) -> Option<usize> {
+    let mut offset = 0;
+    let mut found = None;
+
+    for line in text.split_inclusive('\n') {
+        offset += line.len();
+
+        if predicate(line.trim_end()) {
+            found = Some(offset);
+        }
+    }
+
+    found
+}
@@ -0,0 +1,310 @@
+use std::{
+    fmt::Display,
+    fs::OpenOptions,
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{self, Client, YEAR};
+
+/// How the server judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "verdict", rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// The previous answer was too recent; nothing was checked.
+    Wait {
+        seconds: u64,
+    },
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A response that could not be understood, with its text.
+    Unknown {
+        message: String,
+    },
+}
+
+impl Verdict {
+    /// Reads the verdict from the page the server returns after submitting.
+    pub fn parse(page: &str) -> Self {
+        let message = article_text(page);
+        let text = message.to_lowercase();
+
+        if text.contains("that's the right answer") {
+            Verdict::Correct
+        } else if text.contains("that's not the right answer") {
+            if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("you gave an answer too recently") {
+            Verdict::Wait {
+                seconds: wait_time(&text).unwrap_or_default().as_secs(),
+            }
+        } else if text.contains("you don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown { message }
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::TooHigh => f.write_str("too high"),
+            Verdict::TooLow => f.write_str("too low"),
+            Verdict::Wrong => f.write_str("wrong"),
+            Verdict::Wait { seconds } => write!(f, "submitted too recently, wait {seconds}s"),
+            Verdict::WrongLevel => f.write_str("part is already solved or still locked"),
+            Verdict::Unknown { message } => write!(f, "unexpected response: {message}"),
+        }
+    }
+}
+
+/// Text of the first `<article>` of `page`, or of the whole page if there is none, without
+/// tags and with whitespace collapsed.
+fn article_text(page: &str) -> String {
+    // starts within the opening tag if there is an article
+    let (article, mut in_tag) = match page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+    {
+        Some((article, _)) => (article, true),
+        None => (page, false),
+    };
+
+    let mut text = String::new();
+
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses `you have 1m 5s left to wait`.
+fn wait_time(text: &str) -> Option<Duration> {
+    // the message says "you have to wait" before it gets to the time
+    let (_, rest) = text.rsplit_once("you have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for part in time.split_whitespace() {
+        let (value, factor) = match part.strip_suffix('s') {
+            Some(value) => (value, 1),
+            None => (part.strip_suffix('m')?, 60),
+        };
+
+        seconds += value.parse::<u64>().ok()? * factor;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// One submission, as stored in the log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub verdict: Verdict,
+    /// When the answer was submitted, in milliseconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Every submitted answer, stored as one JSON object per line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionLog {
+    path: PathBuf,
+}
+
+impl SubmissionLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// All attempts, oldest first. A missing file is an empty log.
+    pub fn attempts(&self) -> Result<Vec<Attempt>> {
+        let file = match std::fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Could not open file {}", self.path.display()))
+            }
+        };
+
+        let mut attempts = Vec::new();
+
+        for (idx, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let attempt = serde_json::from_str(&line).with_context(|| {
+                format!("Invalid attempt at {}:{}", self.path.display(), idx + 1)
+            })?;
+
+            attempts.push(attempt);
+        }
+
+        Ok(attempts)
+    }
+
+    pub fn record(&self, attempt: &Attempt) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Could not create directory {}", dir.display()))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Could not open file {}", self.path.display()))?;
+
+        let line = serde_json::to_string(attempt)? + "\n";
+
+        file.write_all(line.as_bytes())
+            .with_context(|| format!("Could not write file {}", self.path.display()))
+    }
+}
+
+/// Why `answer` should not be submitted for `day` and `part`, judging by earlier `attempts`:
+/// the part is solved, the answer is known to be wrong, or the server asked to wait. `now` is
+/// in milliseconds since the Unix epoch.
+pub fn rejection(
+    attempts: &[Attempt],
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Option<String> {
+    let value: Option<i128> = answer.parse().ok();
+
+    for attempt in attempts
+        .iter()
+        .filter(|attempt| attempt.day == day && attempt.part == part)
+    {
+        let earlier = attempt.answer.parse::<i128>().ok();
+
+        match (&attempt.verdict, value, earlier) {
+            (Verdict::Correct, ..) => {
+                return Some(format!(
+                    "Day {day} part {part} is already solved, the answer was {}",
+                    attempt.answer
+                ));
+            }
+            (verdict, ..) if verdict.is_wrong() && attempt.answer == answer => {
+                return Some(format!("{answer} was already submitted and is {verdict}"));
+            }
+            (Verdict::TooHigh, Some(value), Some(earlier)) if value >= earlier => {
+                return Some(format!("{answer} is too high, as {earlier} already was"));
+            }
+            (Verdict::TooLow, Some(value), Some(earlier)) if value <= earlier => {
+                return Some(format!("{answer} is too low, as {earlier} already was"));
+            }
+            _ => {}
+        }
+    }
+
+    let waiting = attempts
+        .iter()
+        .rev()
+        .find_map(|attempt| match attempt.verdict {
+            Verdict::Wait { seconds } => Some(attempt.time + seconds * 1000),
+            _ => None,
+        });
+
+    match waiting {
+        Some(until) if until > now => Some(format!(
+            "The server asked to wait another {}s before submitting",
+            (until - now).div_ceil(1000)
+        )),
+        _ => None,
+    }
+}
+
+/// Submits answers and records them in a [`SubmissionLog`], refusing answers the log already
+/// knows to be wrong.
+#[derive(Debug)]
+pub struct Submitter {
+    client: Client,
+    log: SubmissionLog,
+}
+
+impl Submitter {
+    pub fn new(client: Client, log: SubmissionLog) -> Self {
+        Self { client, log }
+    }
+
+    pub fn log(&self) -> &SubmissionLog {
+        &self.log
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        ensure!((1..=25).contains(&day), "There is no day {day}");
+        ensure!(matches!(part, 1 | 2), "There is no part {part}");
+
+        let answer = answer.trim();
+        ensure!(!answer.is_empty(), "The answer is empty");
+        ensure!(
+            !answer.contains('\n'),
+            "Not submitting a multi-line answer, read the letters and pass them explicitly"
+        );
+
+        let time = client::now().as_millis().try_into().unwrap_or(u64::MAX);
+
+        if let Some(reason) = rejection(&self.log.attempts()?, day, part, answer, time) {
+            bail!("Not submitting: {reason}");
+        }
+
+        let page = self.client.post_form(
+            &format!("/{YEAR}/day/{day}/answer"),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+
+        let verdict = Verdict::parse(&page);
+
+        self.log.record(&Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: verdict.clone(),
+            time,
+        })?;
+
+        Ok(verdict)
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt::Display;
+
+/// Plain text table with a header row. Cells may span multiple lines, in which case the remaining
+/// lines are rendered as continuation rows.
+pub struct Table<const N: usize> {
+    header: [String; N],
+    rows: Vec<[String; N]>,
+}
+
+impl<const N: usize> Table<N> {
+    pub fn new(header: [&str; N]) -> Self {
+        Self {
+            header: header.map(String::from),
+            rows: Vec::default(),
+        }
+    }
+
+    pub fn push(&mut self, row: [String; N]) {
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> [usize; N] {
+        let mut widths = self.header.each_ref().map(|cell| cell.len());
+
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                let cell_width = cell.lines().map(str::len).max().unwrap_or(0);
+                *width = (*width).max(cell_width);
+            }
+        }
+
+        widths
+    }
+}
+
+fn write_row<const N: usize>(
+    f: &mut std::fmt::Formatter<'_>,
+    widths: &[usize; N],
+    row: &[String; N],
+) -> std::fmt::Result {
+    let height = row
+        .iter()
+        .map(|cell| cell.lines().count())
+        .max()
+        .unwrap_or(0);
+
+    for line in 0..height.max(1) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| {
+                let text = cell.lines().nth(line).unwrap_or("");
+                format!("{text:width$}")
+            })
+            .collect();
+
+        writeln!(f, "| {} |", cells.join(" | "))?;
+    }
+
+    Ok(())
+}
+
+impl<const N: usize> Display for Table<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.widths();
+
+        write_row(f, &widths, &self.header)?;
+
+        let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        writeln!(f, "|-{}-|", separator.join("-|-"))?;
+
+        for row in &self.rows {
+            write_row(f, &widths, row)?;
+        }
+
+        Ok(())
+    }
+}
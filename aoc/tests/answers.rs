@@ -0,0 +1,9 @@
+use utils::{workspace_root, Manifest};
+
+#[test]
+fn answers_match_manifest() {
+    let manifest = Manifest::load(&workspace_root().join("answers.toml")).unwrap();
+    let report = manifest.check(&aoc::days::all());
+
+    assert!(report.is_ok(), "{report}");
+}
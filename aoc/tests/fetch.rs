@@ -0,0 +1,129 @@
+use std::{
+    net::TcpStream,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    client::Client,
+    fetch::{Cache, Fetcher, Outcome, Resource},
+    mock::MockServer,
+};
+use tempfile::TempDir;
+
+fn setup() -> (MockServer, TempDir, Fetcher) {
+    let server = MockServer::start().unwrap();
+    server.set_page("/2022/day/1/input", "1000\n2000\n");
+    server.set_page(
+        "/2022/day/1",
+        "<article>--- Day 1: Calorie Counting ---</article>",
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = Client::new(&server.url(), Some("secret".to_string()), dir.path())
+        .min_interval(Duration::ZERO);
+    let fetcher = Fetcher::new(client, Cache::new(dir.path()));
+
+    (server, dir, fetcher)
+}
+
+#[test]
+fn input_is_downloaded_once() {
+    let (server, _dir, fetcher) = setup();
+
+    let fetched = fetcher.fetch(1, Resource::Input, false).unwrap();
+    assert_eq!(fetched.outcome, Outcome::Downloaded);
+    assert_eq!(
+        std::fs::read_to_string(&fetched.path).unwrap(),
+        "1000\n2000\n"
+    );
+
+    let fetched = fetcher.fetch(1, Resource::Input, false).unwrap();
+    assert_eq!(fetched.outcome, Outcome::Cached);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2022/day/1/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+}
+
+#[test]
+fn a_stalled_client_does_not_block_the_server() {
+    let (server, _dir, fetcher) = setup();
+
+    // connects, but never sends its request
+    let _stalled = TcpStream::connect(server.url().trim_start_matches("http://")).unwrap();
+
+    let fetched = fetcher.fetch(1, Resource::Input, false).unwrap();
+    assert_eq!(fetched.outcome, Outcome::Downloaded);
+}
+
+#[test]
+fn description_is_revalidated_with_etag() {
+    let (server, _dir, fetcher) = setup();
+
+    let fetched = fetcher.fetch(1, Resource::Description, false).unwrap();
+    assert_eq!(fetched.outcome, Outcome::Downloaded);
+
+    let fetched = fetcher.fetch(1, Resource::Description, false).unwrap();
+    assert_eq!(fetched.outcome, Outcome::NotModified);
+
+    server.set_page("/2022/day/1", "<article>--- Part Two ---</article>");
+    let fetched = fetcher.fetch(1, Resource::Description, false).unwrap();
+    assert_eq!(fetched.outcome, Outcome::Downloaded);
+    assert_eq!(
+        std::fs::read_to_string(&fetched.path).unwrap(),
+        "<article>--- Part Two ---</article>"
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].header("If-None-Match"), None);
+    assert!(requests[1].header("If-None-Match").is_some());
+}
+
+#[test]
+fn refresh_revalidates_cached_input() {
+    let (server, _dir, fetcher) = setup();
+
+    fetcher.fetch(1, Resource::Input, false).unwrap();
+    let fetched = fetcher.fetch(1, Resource::Input, true).unwrap();
+
+    assert_eq!(fetched.outcome, Outcome::NotModified);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn requests_are_rate_limited() {
+    let (server, dir, _) = setup();
+    let client = Client::new(&server.url(), Some("secret".to_string()), dir.path())
+        .min_interval(Duration::from_millis(300));
+    let fetcher = Fetcher::new(client, Cache::new(dir.path()));
+
+    let start = Instant::now();
+    fetcher.fetch(1, Resource::Input, false).unwrap();
+    fetcher.fetch(1, Resource::Description, false).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn missing_session_fails_without_request() {
+    let (server, dir, _) = setup();
+    let client = Client::new(&server.url(), None, dir.path());
+    let fetcher = Fetcher::new(client, Cache::new(dir.path()));
+
+    let err = fetcher.fetch(1, Resource::Input, false).unwrap_err();
+
+    assert!(err.to_string().contains("AOC_SESSION"), "{err}");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn server_errors_are_reported() {
+    let (_server, _dir, fetcher) = setup();
+
+    let err = fetcher.fetch(2, Resource::Input, false).unwrap_err();
+
+    assert!(err.to_string().contains("404"), "{err}");
+}
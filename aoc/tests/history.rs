@@ -0,0 +1,77 @@
+use std::{fs, thread, time::Duration};
+
+use utils::{Bench, History, Measured, RawParams, Stats};
+
+fn measured(day: u8, input: &str, params: RawParams, millis: u64) -> Measured {
+    let stats = Stats::from_samples(vec![Duration::from_millis(millis)]);
+
+    Measured {
+        input: input.to_string(),
+        params,
+        bench: Bench {
+            day,
+            runs: 1,
+            parse: stats,
+            part1: stats,
+            part2: stats,
+        },
+    }
+}
+
+#[test]
+fn only_results_of_the_same_input_are_compared() {
+    let dir = tempfile::tempdir().unwrap();
+    let history = History::new(dir.path().join("history.jsonl"));
+
+    let mut row = RawParams::default();
+    row.set("row", toml::Value::Integer(10));
+
+    history
+        .record(
+            &[
+                measured(1, "real", RawParams::default(), 10),
+                measured(15, "example", row.clone(), 1),
+            ],
+            Some("before"),
+        )
+        .unwrap();
+    // runs are numbered by the millisecond they are recorded in
+    thread::sleep(Duration::from_millis(2));
+    history
+        .record(
+            &[
+                measured(1, "example", RawParams::default(), 1),
+                measured(15, "example", row.clone(), 2),
+                measured(15, "real", RawParams::default(), 100),
+            ],
+            None,
+        )
+        .unwrap();
+
+    let runs = history.runs().unwrap();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[1].benches[1].params, row);
+
+    let comparisons = utils::compare(&runs[0], &runs[1], 10.0);
+    // day 1 ran on another input, and only the example of day 15 ran on both
+    assert!(comparisons.iter().all(|comparison| comparison.day == 15));
+    assert_eq!(comparisons.len(), 3);
+    assert!(comparisons.iter().all(|comparison| comparison.regression));
+    assert_eq!(comparisons[0].baseline, Duration::from_millis(1));
+}
+
+#[test]
+fn entries_without_an_input_are_still_read() {
+    let dir = tempfile::tempdir().unwrap();
+    let history = History::new(dir.path().join("history.jsonl"));
+    let stats = r#"{"min_ns":1,"median_ns":1,"p95_ns":1,"max_ns":1}"#;
+    fs::write(
+        history.path(),
+        format!(r#"{{"run":1,"day":1,"runs":1,"parse":{stats},"part1":{stats},"part2":{stats}}}"#),
+    )
+    .unwrap();
+
+    let runs = history.runs().unwrap();
+    assert_eq!(runs[0].benches[0].input, "");
+    assert!(runs[0].benches[0].params.is_empty());
+}
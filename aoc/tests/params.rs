@@ -0,0 +1,149 @@
+use std::fs;
+
+use aoc::days;
+use utils::{workspace_root, Manifest, Overrides, Param, RawParams};
+
+fn example(number: u8) -> (String, RawParams) {
+    let manifest = Manifest::load(&workspace_root().join("answers.toml")).unwrap();
+    let input = manifest.input(number, "example").unwrap();
+
+    (input.read().unwrap(), input.params)
+}
+
+#[test]
+fn params_are_read_as_toml() {
+    let param: Param = "row=10".parse().unwrap();
+    assert_eq!((param.day, param.key.as_str()), (None, "row"));
+    assert_eq!(param.value, toml::Value::Integer(10));
+
+    let param: Param = "cycles=[20, 60]".parse().unwrap();
+    assert!(param.value.is_array());
+
+    // not valid toml, so taken as it is
+    let param: Param = "start=AA".parse().unwrap();
+    assert_eq!(param.value, toml::Value::String("AA".to_string()));
+
+    assert!("row".parse::<Param>().is_err());
+    assert!("=10".parse::<Param>().is_err());
+}
+
+#[test]
+fn overrides_replace_params_of_the_input() {
+    let (input, params) = example(15);
+
+    let mut overrides = Overrides::default();
+    overrides.set("row=11".parse().unwrap());
+
+    let answers = days::get(15)
+        .unwrap()
+        .solve(&input, &overrides.apply(15, params))
+        .unwrap();
+
+    // the bound of the example is kept
+    assert_eq!(answers.part1.unwrap(), "28");
+    assert_eq!(answers.part2.unwrap(), "56000011");
+}
+
+#[test]
+fn config_file_applies_to_its_day() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("params.toml");
+    fs::write(&path, "[day_1]\ntop = 2\n\n[day_15]\nrow = 11\n").unwrap();
+
+    let overrides = Overrides::load(&path).unwrap();
+    let (input, params) = example(1);
+
+    let answers = days::get(1)
+        .unwrap()
+        .solve(&input, &overrides.apply(1, params))
+        .unwrap();
+    assert_eq!(answers.part2.unwrap(), "35000");
+
+    fs::write(&path, "[fifteen]\nrow = 11\n").unwrap();
+    assert!(Overrides::load(&path).is_err());
+}
+
+#[test]
+fn unknown_params_are_rejected() {
+    let (input, mut params) = example(15);
+    params.set("rows", toml::Value::Integer(11));
+
+    let err = days::get(15).unwrap().solve(&input, &params).unwrap_err();
+    assert!(
+        format!("{err:#}").contains("unknown field `rows`"),
+        "{err:#}"
+    );
+
+    // days without parameters take none
+    let (input, mut params) = example(2);
+    params.set("top", toml::Value::Integer(1));
+
+    assert!(days::get(2).unwrap().solve(&input, &params).is_err());
+}
+
+#[test]
+fn params_can_be_scoped_to_a_day() {
+    let param: Param = "day_1.top=2".parse().unwrap();
+    assert_eq!((param.day, param.key.as_str()), (Some(1), "top"));
+    assert!("day_one.top=2".parse::<Param>().is_err());
+
+    let mut overrides = Overrides::default();
+    overrides.set(param);
+    overrides.ensure_scoped().unwrap();
+
+    let (input, params) = example(1);
+    let answers = days::get(1)
+        .unwrap()
+        .solve(&input, &overrides.apply(1, params))
+        .unwrap();
+    assert_eq!(answers.part2.unwrap(), "35000");
+
+    // other days do not see it
+    let (input, params) = example(15);
+    assert!(days::get(15)
+        .unwrap()
+        .solve(&input, &overrides.apply(15, params))
+        .is_ok());
+
+    overrides.set("top=2".parse().unwrap());
+    let err = overrides.ensure_scoped().unwrap_err();
+    assert!(err.to_string().contains("`day_N.top=...`"), "{err:#}");
+}
+
+#[test]
+fn invalid_params_are_errors_not_panics() {
+    let (input, mut params) = example(6);
+    params.set("packet_marker", toml::Value::Integer(0));
+
+    let answers = days::get(6).unwrap().solve(&input, &params).unwrap();
+    assert!(answers.part1.is_err());
+    assert!(answers.part2.is_ok());
+
+    // the distress beacon of the example is outside this bound
+    let (input, mut params) = example(15);
+    params.set("bound", toml::Value::Integer(5));
+
+    let err = days::get(15)
+        .unwrap()
+        .solve(&input, &params)
+        .unwrap()
+        .part2
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "No position within 0..=5 is out of reach of every sensor"
+    );
+
+    // far away positions have a tuning frequency too large for isize
+    params.set("bound", toml::Value::Integer(i64::MAX));
+    let err = days::get(15)
+        .unwrap()
+        .solve(&input, &params)
+        .unwrap()
+        .part2
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("does not fit into isize"),
+        "{err:#}"
+    );
+}
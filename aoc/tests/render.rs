@@ -0,0 +1,107 @@
+use std::{fs, time::Duration};
+
+use aoc::days;
+use utils::{workspace_root, Animation, Color, Frame, Manifest, Pixel, Point, Recorder};
+
+/// Frames of the example of `number`, keeping all of them.
+fn animate_example(number: u8) -> Vec<Frame> {
+    let manifest = Manifest::load(&workspace_root().join("answers.toml")).unwrap();
+    let input = manifest.input(number, "example").unwrap();
+
+    let mut recorder = Recorder::new(1, 10_000);
+    days::get(number)
+        .unwrap()
+        .animate(&input.read().unwrap(), &input.params, &mut recorder)
+        .unwrap();
+
+    recorder.frames().to_vec()
+}
+
+fn numbered(number: usize) -> Frame {
+    Frame::from_text(&number.to_string(), |_| Color::WHITE)
+}
+
+#[test]
+fn frames_are_drawn_from_text() {
+    let frame = Frame::from_text("#.\n#", |ch| match ch {
+        '#' => Color::RED,
+        _ => Color::GREY,
+    });
+
+    assert_eq!((frame.width(), frame.height()), (2, 2));
+    assert_eq!(
+        frame.get(Point::new(0, 1)),
+        Some(&Pixel::new('#', Color::RED))
+    );
+    assert_eq!(frame.get(Point::new(1, 1)), Some(&Pixel::BLANK));
+    assert_eq!(frame.to_string(), "#.\n# \n");
+
+    let colored = frame.colored();
+    assert!(colored.starts_with("\x1b[38;2;220;50;47m#\x1b[38;2;128;128;128m."));
+}
+
+#[test]
+fn recorder_thins_out_long_simulations() {
+    let mut recorder = Recorder::new(1, 4);
+    for number in 0..10 {
+        recorder.record_with(|| numbered(number));
+    }
+    recorder.finish_with(|| numbered(9));
+
+    let kept: Vec<String> = recorder.frames().iter().map(Frame::to_string).collect();
+    assert_eq!(kept, ["0\n", "4\n", "8\n", "9\n"]);
+
+    // the last frame is not repeated if it was kept anyway
+    let mut recorder = Recorder::new(2, 100);
+    for number in 0..5 {
+        recorder.record_with(|| numbered(number));
+    }
+    recorder.finish_with(|| numbered(4));
+
+    assert_eq!(recorder.frames().len(), 3);
+}
+
+#[test]
+fn days_replay_their_simulation() {
+    // a frame for every grain of sand coming to rest
+    let frames = animate_example(14);
+    assert_eq!(frames.len(), 24);
+    assert!(frames[23].to_string().ends_with("#########.\n"));
+
+    // the rope of part 2 ends where the puzzle draws it
+    let frames = animate_example(9);
+    assert!(frames[frames.len() - 1].to_string().contains('H'));
+
+    let err = days::get(1)
+        .unwrap()
+        .animate("1000\n", &Default::default(), &mut Recorder::new(1, 10))
+        .unwrap_err();
+    assert!(err.to_string().contains("no animation"), "{err:#}");
+}
+
+#[test]
+fn animations_are_saved_by_extension() {
+    let dir = tempfile::tempdir().unwrap();
+    let animation = Animation {
+        frames: animate_example(10),
+        delay: Duration::from_millis(20),
+    };
+
+    let gif = dir.path().join("crt.gif");
+    animation.save(&gif, 2).unwrap();
+    assert!(fs::read(&gif).unwrap().starts_with(b"GIF89a"));
+
+    let png = dir.path().join("crt.png");
+    animation.save(&png, 2).unwrap();
+    assert!(fs::read(&png).unwrap().starts_with(b"\x89PNG"));
+
+    let txt = dir.path().join("crt.txt");
+    animation.save(&txt, 1).unwrap();
+    assert!(fs::read_to_string(&txt)
+        .unwrap()
+        .contains("##..##..##..##..##..##..##..##..##..##.."));
+
+    let bmp = dir.path().join("crt.bmp");
+    assert!(animation.save(&bmp, 2).is_err());
+    assert!(!bmp.exists());
+}
@@ -0,0 +1,26 @@
+use std::process::{Command, Output};
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn failing_days_make_the_run_fail() {
+    let output = aoc(&["run", "9", "--named", "example"]);
+    assert!(output.status.success());
+
+    let output = aoc(&["run", "9", "--named", "example", "--param", "part1_knots=0"]);
+    assert!(!output.status.success());
+
+    // the table is still printed, with the part that could be solved
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("error: A rope needs at least one knot"),
+        "{stdout}"
+    );
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("1 day(s) could not be solved"), "{stderr}");
+}
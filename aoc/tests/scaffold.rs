@@ -0,0 +1,118 @@
+use std::{fs, path::Path};
+
+use aoc::scaffold;
+use tempfile::TempDir;
+use utils::workspace_root;
+
+/// A workspace with copies of the files listing the days, but without any day crates.
+fn setup() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let root = workspace_root();
+
+    for file in [
+        "Cargo.toml",
+        "answers.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/days.rs",
+    ] {
+        let target = dir.path().join(file);
+        fs::create_dir_all(target.parent().unwrap()).unwrap();
+        fs::copy(root.join(file), target).unwrap();
+    }
+
+    dir
+}
+
+fn read(root: &Path, file: &str) -> String {
+    fs::read_to_string(root.join(file)).unwrap()
+}
+
+#[test]
+fn day_is_created_and_registered() {
+    let dir = setup();
+    let root = dir.path();
+
+    let files = scaffold::new_day(root, 17, "Volcano").unwrap();
+    assert_eq!(files.len(), 9);
+
+    assert!(read(root, "day_17/Cargo.toml").contains("name = \"day_17\""));
+    assert!(read(root, "day_17/src/lib.rs").contains("impl Solver for Volcano"));
+    assert!(read(root, "day_17/src/lib.rs").contains("const DAY: u8 = 17;"));
+    assert!(read(root, "day_17/src/main.rs").contains("utils::run::<day_17::Volcano>()"));
+    assert_eq!(read(root, "day_17/input.txt"), "");
+    assert_eq!(read(root, "day_17/example.txt"), "");
+
+    assert!(read(root, "Cargo.toml").contains("    \"day_16\",\n    \"day_17\",\n]"));
+    assert!(read(root, "aoc/Cargo.toml")
+        .contains("day_16 = { path = \"../day_16\" }\nday_17 = { path = \"../day_17\" }\n"));
+    assert!(read(root, "aoc/src/days.rs").contains(
+        "        Day::new::<day_16::Valve>(),\n        Day::new::<day_17::Volcano>(),\n    ]"
+    ));
+    let answers = read(root, "answers.toml");
+    assert!(answers.contains("# day = 17\n# part = 2\n# input = \"real\"\n"));
+    assert!(answers.contains("name = \"example\"\npath = \"day_17/example.txt\"\n"));
+
+    let manifest = utils::Manifest::load(&root.join("answers.toml")).unwrap();
+    assert_eq!(
+        manifest.input(17, "example").unwrap().path,
+        root.join("day_17/example.txt")
+    );
+}
+
+#[test]
+fn existing_day_is_refused() {
+    let dir = setup();
+    let root = dir.path();
+    let manifest = read(root, "Cargo.toml");
+
+    let err = scaffold::new_day(root, 16, "Valve").unwrap_err();
+
+    assert!(format!("{err:#}").contains("already"), "{err:#}");
+    assert_eq!(read(root, "Cargo.toml"), manifest);
+    assert!(!root.join("day_16").exists());
+}
+
+#[test]
+fn nothing_is_written_on_failure() {
+    let dir = setup();
+    let root = dir.path();
+    fs::remove_file(root.join("aoc/src/days.rs")).unwrap();
+    let manifest = read(root, "Cargo.toml");
+
+    assert!(scaffold::new_day(root, 17, "Volcano").is_err());
+
+    assert_eq!(read(root, "Cargo.toml"), manifest);
+    assert!(!root.join("day_17").exists());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn written_files_are_restored_when_a_write_fails() {
+    let dir = setup();
+    let root = dir.path();
+    let before: Vec<String> = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"]
+        .map(|file| read(root, file))
+        .to_vec();
+
+    // readable, but not even root can write it
+    fs::remove_file(root.join("answers.toml")).unwrap();
+    std::os::unix::fs::symlink("/proc/version", root.join("answers.toml")).unwrap();
+
+    let err = scaffold::new_day(root, 17, "Volcano").unwrap_err();
+
+    assert!(format!("{err:#}").contains("answers.toml"), "{err:#}");
+    let after: Vec<String> = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"]
+        .map(|file| read(root, file))
+        .to_vec();
+    assert_eq!(after, before);
+    assert!(!root.join("day_17").exists());
+}
+
+#[test]
+fn invalid_arguments_are_refused() {
+    let dir = setup();
+
+    assert!(scaffold::new_day(dir.path(), 26, "Puzzle").is_err());
+    assert!(scaffold::new_day(dir.path(), 17, "puzzle").is_err());
+    assert!(scaffold::new_day(dir.path(), 17, "Two Words").is_err());
+}
@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use aoc::{
+    client::Client,
+    mock::MockServer,
+    submit::{rejection, Attempt, SubmissionLog, Submitter, Verdict},
+};
+use tempfile::TempDir;
+
+fn setup() -> (MockServer, TempDir, Submitter) {
+    let server = MockServer::start().unwrap();
+    server.set_answer(1, 1, "68923");
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = Client::new(&server.url(), Some("secret".to_string()), dir.path())
+        .min_interval(Duration::ZERO);
+    let log = SubmissionLog::new(dir.path().join("submissions.jsonl"));
+
+    (server, dir, Submitter::new(client, log))
+}
+
+#[test]
+fn verdicts_are_parsed_from_the_article() {
+    let page = |message: &str| format!("<html><main><article><p>{message}</p></article></main>");
+
+    assert_eq!(
+        Verdict::parse(&page(
+            "That's the right answer!  You are <em>one gold star</em> closer."
+        )),
+        Verdict::Correct
+    );
+    assert_eq!(
+        Verdict::parse(&page(
+            "That's not the right answer; your answer is too high."
+        )),
+        Verdict::TooHigh
+    );
+    assert_eq!(
+        Verdict::parse(&page("That's not the right answer.  If you're stuck, ...")),
+        Verdict::Wrong
+    );
+    assert_eq!(
+        Verdict::parse(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 5s left to wait."
+        )),
+        Verdict::Wait { seconds: 65 }
+    );
+    assert_eq!(
+        Verdict::parse(&page("You don't seem to be solving the right level.")),
+        Verdict::WrongLevel
+    );
+    assert!(matches!(
+        Verdict::parse("Something else"),
+        Verdict::Unknown { .. }
+    ));
+}
+
+#[test]
+fn attempts_are_judged_and_logged() {
+    let (server, _dir, submitter) = setup();
+
+    assert_eq!(submitter.submit(1, 1, "70000").unwrap(), Verdict::TooHigh);
+    assert_eq!(submitter.submit(1, 1, "60000").unwrap(), Verdict::TooLow);
+    assert_eq!(submitter.submit(1, 1, "68923").unwrap(), Verdict::Correct);
+
+    let attempts = submitter.log().attempts().unwrap();
+    let verdicts: Vec<_> = attempts.iter().map(|attempt| &attempt.verdict).collect();
+    assert_eq!(
+        verdicts,
+        [&Verdict::TooHigh, &Verdict::TooLow, &Verdict::Correct]
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/1/answer");
+    assert_eq!(requests[0].body, "level=1&answer=70000");
+}
+
+#[test]
+fn known_wrong_answers_are_not_submitted() {
+    let (server, _dir, submitter) = setup();
+
+    submitter.submit(1, 1, "70000").unwrap();
+
+    // the same answer, and anything higher than a too high one
+    assert!(submitter.submit(1, 1, "70000").is_err());
+    assert!(submitter.submit(1, 1, "80000").is_err());
+    assert_eq!(server.requests().len(), 1);
+
+    submitter.submit(1, 1, "68923").unwrap();
+    let err = submitter.submit(1, 1, "68923").unwrap_err();
+
+    assert!(err.to_string().contains("already solved"), "{err}");
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn multi_line_answers_are_not_submitted() {
+    let (server, _dir, submitter) = setup();
+    let drawing = "##..##..\n#..#.#..\n##..##..\n";
+
+    let err = submitter.submit(10, 2, drawing).unwrap_err();
+
+    assert!(err.to_string().contains("multi-line"), "{err}");
+    assert!(server.requests().is_empty());
+    assert!(submitter.log().attempts().unwrap().is_empty());
+}
+
+#[test]
+fn waiting_is_respected() {
+    let (server, _dir, submitter) = setup();
+    server.set_cooldown(Duration::from_secs(60));
+
+    submitter.submit(1, 1, "1").unwrap();
+    let verdict = submitter.submit(1, 1, "2").unwrap();
+    assert!(
+        matches!(verdict, Verdict::Wait { seconds } if seconds > 50),
+        "{verdict:?}"
+    );
+
+    let err = submitter.submit(1, 1, "3").unwrap_err();
+    assert!(err.to_string().contains("wait"), "{err}");
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn wait_expires() {
+    let attempt = Attempt {
+        day: 1,
+        part: 2,
+        answer: "1".to_string(),
+        verdict: Verdict::Wait { seconds: 30 },
+        time: 1_000_000,
+    };
+
+    let attempts = [attempt];
+
+    assert!(rejection(&attempts, 1, 2, "5", 1_010_000).is_some());
+    assert!(rejection(&attempts, 1, 2, "5", 1_031_000).is_none());
+}
@@ -0,0 +1,69 @@
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
+use utils::{workspace_root, Manifest};
+
+/// Output of the events, shared with the subscriber.
+#[derive(Debug, Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Events printed while solving the example of `number` with every level enabled.
+fn trace_example(number: u8) -> String {
+    let manifest = Manifest::load(&workspace_root().join("answers.toml")).unwrap();
+    let input = manifest.input(number, "example").unwrap();
+    let day = aoc::days::get(number).unwrap();
+
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(Level::TRACE)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    tracing::subscriber::with_default(subscriber, || {
+        day.solve(&input.read().unwrap(), &input.params).unwrap()
+    });
+
+    let output = buffer.0.lock().unwrap().clone();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn stages_run_in_spans() {
+    let output = trace_example(1);
+
+    assert!(
+        output.contains("parse{day=1}: utils::trace: close"),
+        "{output}"
+    );
+    assert!(output.contains("part{day=1 part=1}: utils::trace: solved answer=24000"));
+    assert!(output.contains("part{day=1 part=2}: utils::trace: solved answer=45000"));
+}
+
+#[test]
+fn simulations_trace_their_steps() {
+    let output = trace_example(14);
+
+    // tests are built without optimizations, which keep the `trace` events
+    assert!(
+        output.contains("part{day=14 part=1}: day_14: fall pos=Point { x: 500, y: 1 }"),
+        "{output}"
+    );
+    assert!(output.contains("resting=24"));
+}
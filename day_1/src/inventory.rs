@@ -0,0 +1,218 @@
+//! Reading the inventories, either strictly like the puzzle expects or leniently for inputs typed
+//! by hand.
+
+use std::io::BufRead;
+
+use anyhow::{Context, Error, Result};
+use utils::ParseError;
+
+use crate::Elf;
+
+/// How [`Inventories`] treats lines that do not follow the format of the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Fails on the first problem.
+    #[default]
+    Strict,
+    /// Skips lines it cannot make sense of, collecting a warning for each problem instead.
+    Lenient,
+}
+
+/// The elves of an input, and what was wrong with it in [`Mode::Lenient`].
+#[derive(Debug)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+    pub warnings: Vec<Error>,
+}
+
+/// Elves read from inventories separated by a single blank line like the puzzle input. Only one
+/// inventory is held at a time, so the input can be far larger than memory.
+///
+/// Problems are reported as a [`ParseError`] for their line, in the context of the elf the line
+/// belongs to. Elves are numbered from 1 in input order, and a blank line counts towards the elf
+/// after it. In [`Mode::Lenient`], extra blank lines are ignored, trailing whitespace is trimmed
+/// and items that are not a valid number are skipped. An elf whose items are all skipped is kept
+/// with an empty inventory, so the elves keep the numbers they have in the input.
+#[derive(Debug)]
+pub struct Inventories<R> {
+    reader: R,
+    mode: Mode,
+    line: String,
+    // lines read so far, for errors
+    line_number: usize,
+    elves: usize,
+    warnings: Vec<Error>,
+    failed: bool,
+}
+
+impl<R: BufRead> Inventories<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_mode(reader, Mode::Strict)
+    }
+
+    pub fn with_mode(reader: R, mode: Mode) -> Self {
+        Self {
+            reader,
+            mode,
+            line: String::new(),
+            line_number: 0,
+            elves: 0,
+            warnings: Vec::new(),
+            failed: false,
+        }
+    }
+
+    /// The problems skipped so far in [`Mode::Lenient`].
+    pub fn warnings(&self) -> &[Error] {
+        &self.warnings
+    }
+
+    pub fn into_warnings(self) -> Vec<Error> {
+        self.warnings
+    }
+
+    /// The next inventory, `None` at the end of the input.
+    fn next_inventory(&mut self) -> Result<Option<Elf>> {
+        // set once a line of the elf was seen, even if none of its items are valid
+        let mut bag: Option<Vec<usize>> = None;
+        let mut total = 0usize;
+
+        loop {
+            self.line.clear();
+            let read = self.reader.read_line(&mut self.line).with_context(|| {
+                format!("Could not read line {} of the input", self.line_number + 1)
+            })?;
+
+            if read == 0 {
+                return Ok(self.finish(bag));
+            }
+
+            self.line_number += 1;
+            let report = Report {
+                mode: self.mode,
+                elf: self.elves + 1,
+                line_number: self.line_number,
+                source: &self.line,
+            };
+
+            // CRLF line endings are not trailing whitespace
+            let raw = self.line.trim_end_matches('\n');
+            let raw = raw.strip_suffix('\r').unwrap_or(raw);
+            let line = raw.trim_end();
+
+            if line.len() != raw.len() {
+                report.problem(&raw[line.len()..], "end of line", &mut self.warnings)?;
+            }
+
+            if line.is_empty() {
+                if bag.is_some() {
+                    return Ok(self.finish(bag));
+                }
+
+                let expected = if self.elves == 0 {
+                    "the calories of an item"
+                } else {
+                    "the calories of an item (inventories are separated by a single blank line)"
+                };
+                report.problem(line, expected, &mut self.warnings)?;
+
+                continue;
+            }
+
+            let items = bag.get_or_insert_with(Vec::new);
+            let calories = match item(line) {
+                Ok(calories) => calories,
+                Err(expected) => {
+                    report.problem(line, expected, &mut self.warnings)?;
+                    continue;
+                }
+            };
+
+            match total.checked_add(calories) {
+                Some(sum) => {
+                    items.push(calories);
+                    total = sum;
+                }
+                None => report.problem(
+                    line,
+                    "calories that add up to a total fitting into usize",
+                    &mut self.warnings,
+                )?,
+            }
+        }
+    }
+
+    fn finish(&mut self, bag: Option<Vec<usize>>) -> Option<Elf> {
+        let bag = bag?;
+        self.elves += 1;
+
+        Some(Elf { bag })
+    }
+}
+
+impl<R: BufRead> Iterator for Inventories<R> {
+    type Item = Result<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let res = self.next_inventory();
+        self.failed = res.is_err();
+
+        res.transpose()
+    }
+}
+
+/// Where in the input a problem is, and what to do with it.
+struct Report<'a> {
+    mode: Mode,
+    elf: usize,
+    line_number: usize,
+    // the line being read, including its line break
+    source: &'a str,
+}
+
+impl Report<'_> {
+    /// Fails with an error pointing at `span` in [`Mode::Strict`], or adds it to `warnings`.
+    fn problem(&self, span: &str, expected: &str, warnings: &mut Vec<Error>) -> Result<()> {
+        let error = ParseError::at(self.source, span, expected).at_line(self.line_number);
+        let error = Error::new(error).context(format!("Invalid inventory of elf {}", self.elf));
+
+        match self.mode {
+            Mode::Strict => Err(error),
+            Mode::Lenient => {
+                warnings.push(error);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The calories of an item, or what was expected instead.
+fn item(line: &str) -> Result<usize, &'static str> {
+    let digits = |text: &str| !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit());
+
+    if !digits(line) {
+        return Err(if line.strip_prefix('-').is_some_and(digits) {
+            "calories that are not negative"
+        } else {
+            "the calories of an item"
+        });
+    }
+
+    // only digits, so the number can only be too large
+    line.parse().map_err(|_| "calories that fit into usize")
+}
+
+/// Reads all of `input`, see [`Inventories`].
+pub fn parse(input: &str, mode: Mode) -> Result<Inventory> {
+    let mut inventories = Inventories::with_mode(input.as_bytes(), mode);
+    let elves = inventories.by_ref().collect::<Result<_>>()?;
+
+    Ok(Inventory {
+        elves,
+        warnings: inventories.into_warnings(),
+    })
+}
@@ -0,0 +1,140 @@
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+use anyhow::Result;
+use inventory::Mode;
+use serde::Deserialize;
+use utils::Solver;
+
+pub mod inventory;
+pub mod stats;
+
+pub use inventory::Inventories;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf {
+    bag: Vec<usize>,
+}
+
+impl Elf {
+    /// Calories of all the items the elf carries.
+    pub fn cal_count(&self) -> usize {
+        self.bag.iter().sum()
+    }
+}
+
+/// An elf among the ones carrying the most calories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranked {
+    /// Position of the elf's inventory in the input, starting at 1.
+    pub elf: usize,
+    pub calories: usize,
+}
+
+/// The `k` elves carrying the most calories of the ones pushed so far. Only those `k` are kept,
+/// so any number of elves can be pushed without sorting or storing them all. Of elves carrying
+/// the same calories, the earlier one ranks higher.
+#[derive(Debug, Clone)]
+pub struct TopElves {
+    k: usize,
+    // a min-heap by rank, so the elf to drop next is on top
+    heap: BinaryHeap<Reverse<(usize, Reverse<usize>)>>,
+}
+
+impl TopElves {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Ranks the elves in `calories`, given in input order.
+    pub fn of(calories: impl IntoIterator<Item = usize>, k: usize) -> Self {
+        let mut top = Self::new(k);
+
+        for (idx, calories) in calories.into_iter().enumerate() {
+            top.push(idx + 1, calories);
+        }
+
+        top
+    }
+
+    pub fn push(&mut self, elf: usize, calories: usize) {
+        let entry = Reverse((calories, Reverse(elf)));
+
+        if self.heap.len() < self.k {
+            self.heap.push(entry);
+        } else if let Some(mut lowest) = self.heap.peek_mut() {
+            // ordered in reverse, so smaller means ranked higher
+            if entry < *lowest {
+                *lowest = entry;
+            }
+        }
+    }
+
+    /// Calories carried by the top elves together.
+    pub fn total(&self) -> usize {
+        self.heap
+            .iter()
+            .map(|Reverse((calories, _))| calories)
+            .sum()
+    }
+
+    /// The top elves, the one carrying the most calories first.
+    pub fn into_ranking(self) -> Vec<Ranked> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(elf)))| Ranked { elf, calories })
+            .collect()
+    }
+}
+
+/// The `k` elves carrying the most calories in the inventories read from `reader`.
+pub fn top_elves_in(reader: impl BufRead, k: usize) -> Result<TopElves> {
+    let mut top = TopElves::new(k);
+
+    for (idx, elf) in Inventories::new(reader).enumerate() {
+        top.push(idx + 1, elf?.cal_count());
+    }
+
+    Ok(top)
+}
+
+fn top(elves: &[Elf], count: usize) -> usize {
+    TopElves::of(elves.iter().map(Elf::cal_count), count).total()
+}
+
+/// How many of the elves carrying the most calories part 2 adds up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub top: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { top: 3 }
+    }
+}
+
+impl Solver for Elf {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<Elf>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(inventory::parse(input, Mode::Strict)?.elves)
+    }
+
+    fn part1(elves: &Self::Parsed, _: &Params) -> Result<usize> {
+        Ok(top(elves, 1))
+    }
+
+    fn part2(elves: &Self::Parsed, params: &Params) -> Result<usize> {
+        Ok(top(elves, params.top))
+    }
+}
@@ -1,37 +1,66 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use clap::Parser;
+use day_1::{
+    inventory::{self, Inventory, Mode},
+    stats::Report,
+    Elf, Params,
+};
+use utils::{Args, Format, Solver};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Elf {
-    bag: Vec<usize>,
-}
+#[derive(Debug, Parser)]
+#[command(about = "Solves day 1 of Advent of Code 2022, or reports what the elves carry")]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
 
-impl Elf {
-    fn cal_count(&self) -> usize {
-        self.bag.iter().sum()
-    }
+    /// Print statistics of the calories each elf carries instead of the answers, looking for
+    /// ties at the position of the `top` parameter
+    #[arg(long, conflicts_with = "bench")]
+    stats: bool,
+
+    /// Number of buckets in the histogram of the statistics, at most one per distinct total
+    #[arg(long, value_name = "COUNT", default_value_t = 10, requires = "stats")]
+    buckets: usize,
+
+    /// Skip the lines that are not valid items instead of failing, printing a warning to stderr
+    /// for each problem with the input
+    #[arg(long, conflicts_with = "bench")]
+    lenient: bool,
 }
 
 fn main() -> Result<()> {
-    let input = utils::read_file()?;
+    utils::init_tracing();
 
-    let mut iter = input.lines().peekable();
+    let cli = Cli::parse();
+    if !cli.stats && !cli.lenient {
+        return utils::run_with::<Elf>(&cli.args);
+    }
+
+    let (input, params) = cli.args.load(Elf::DAY)?;
+    let params: Params = params.get()?;
 
-    let mut elves: Vec<Elf> = Vec::new();
+    let elves = if cli.lenient {
+        let Inventory { elves, warnings } = inventory::parse(&input, Mode::Lenient)?;
+        for warning in warnings {
+            eprintln!("Warning: {warning:#}");
+        }
 
-    while iter.peek().is_some() {
-        let calories: Vec<usize> = iter
-            .by_ref()
-            .take_while(|line| !line.is_empty())
-            .map(|line| line.parse::<usize>())
-            .collect::<Result<_, _>>()?;
+        elves
+    } else {
+        Elf::parse(&input)?
+    };
 
-        elves.push(Elf { bag: calories })
+    if !cli.stats {
+        return utils::print_answers::<Elf>(&cli.args, &elves, &params);
     }
 
-    elves.sort_by_key(|elf1| elf1.cal_count());
+    let report = Report::new(&elves, params.top, cli.buckets)?;
 
-    let count: usize = elves.iter().rev().take(3).map(|elf| elf.cal_count()).sum();
-    println!("Total calories by top three elves: {count}");
+    match cli.args.format {
+        Format::Text => print!("{report}"),
+        Format::Json => println!("{}", serde_json::to_string(&report)?),
+        Format::Csv => bail!("Statistics can be printed as text or JSON, not CSV"),
+    }
 
     Ok(())
 }
@@ -0,0 +1,239 @@
+//! Statistics of the calories the elves carry, beyond the few elves the puzzle asks about.
+
+use std::{cmp::Reverse, fmt::Display};
+
+use anyhow::{anyhow, ensure, Result};
+use serde::Serialize;
+
+use crate::Elf;
+
+/// Percentiles of the totals included in a [`Report`].
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Calories carried by the elves of an input. Elves are numbered from 1 in input order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub elves: usize,
+    pub items: usize,
+    pub total: usize,
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Standard deviation of the totals over all elves, not a sample.
+    pub std_dev: f64,
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bucket>,
+    pub largest_item: Item,
+    /// The elves tied with the one in position `k`, if there are at least `k` elves.
+    pub kth: Option<Tie>,
+}
+
+/// The total below which `percent` of the elves are, interpolated between neighbouring totals.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Percentile {
+    pub percent: u8,
+    pub calories: f64,
+}
+
+/// Elves carrying from `from` up to, but excluding, `to` calories. The last bucket includes `to`,
+/// which is the most any elf carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: usize,
+    pub to: usize,
+    pub elves: usize,
+}
+
+/// A single item, and the first elf carrying it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Item {
+    pub elf: usize,
+    pub calories: usize,
+}
+
+/// The elves carrying as many calories as the elf in position `k` when ranked by calories, of
+/// which only `in_top` make it into the top `k`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Tie {
+    pub k: usize,
+    pub calories: usize,
+    pub elves: Vec<usize>,
+    pub in_top: usize,
+}
+
+impl Report {
+    /// Statistics of `elves` with a histogram of up to `buckets` equally wide buckets, looking for
+    /// ties at position `k`.
+    pub fn new(elves: &[Elf], k: usize, buckets: usize) -> Result<Self> {
+        ensure!(!elves.is_empty(), "There are no elves to report on");
+        ensure!(buckets > 0, "The histogram needs at least one bucket");
+
+        let totals: Vec<usize> = elves.iter().map(Elf::cal_count).collect();
+        let mut sorted = totals.clone();
+        sorted.sort_unstable();
+
+        let total = totals
+            .iter()
+            .try_fold(0usize, |sum, &calories| sum.checked_add(calories))
+            .ok_or_else(|| anyhow!("The elves carry more calories than fit into usize"))?;
+        let mean = total as f64 / totals.len() as f64;
+        let variance = totals
+            .iter()
+            .map(|&calories| (calories as f64 - mean).powi(2))
+            .sum::<f64>()
+            / totals.len() as f64;
+
+        let largest_item = elves
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, elf)| elf.bag.iter().map(move |&calories| (idx + 1, calories)))
+            // the first of the largest items
+            .min_by_key(|&(elf, calories)| (Reverse(calories), elf))
+            .map(|(elf, calories)| Item { elf, calories })
+            .ok_or_else(|| anyhow!("The elves carry no items"))?;
+
+        Ok(Self {
+            elves: elves.len(),
+            items: elves.iter().map(|elf| elf.bag.len()).sum(),
+            total,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            mean,
+            median: percentile(&sorted, 50),
+            std_dev: variance.sqrt(),
+            percentiles: PERCENTILES
+                .into_iter()
+                .map(|percent| Percentile {
+                    percent,
+                    calories: percentile(&sorted, percent),
+                })
+                .collect(),
+            histogram: histogram(&sorted, buckets),
+            largest_item,
+            kth: tie(&totals, &sorted, k),
+        })
+    }
+}
+
+/// Linear interpolation between the closest ranks of `sorted`, which must not be empty.
+fn percentile(sorted: &[usize], percent: u8) -> f64 {
+    let rank = (sorted.len() - 1) as f64 * percent as f64 / 100.0;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+
+    let (below, above) = (sorted[below] as f64, sorted[above] as f64);
+    below + (above - below) * rank.fract()
+}
+
+/// At most `buckets` buckets, and no more than there are distinct totals, so a huge count does
+/// not make up buckets that can only stay empty.
+fn histogram(sorted: &[usize], buckets: usize) -> Vec<Bucket> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let distinct = 1 + sorted.windows(2).filter(|pair| pair[0] != pair[1]).count();
+    let buckets = buckets.min(distinct);
+    let width = (max - min).div_ceil(buckets).max(1);
+
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map_while(|idx| {
+            let from = min.checked_add(idx.checked_mul(width)?)?;
+
+            // rounding up the width can leave the last buckets past the largest total
+            (from <= max).then(|| Bucket {
+                from,
+                to: from.saturating_add(width).min(max),
+                elves: 0,
+            })
+        })
+        .collect();
+
+    let last = histogram.len() - 1;
+    for &calories in sorted {
+        histogram[((calories - min) / width).min(last)].elves += 1;
+    }
+
+    histogram
+}
+
+fn tie(totals: &[usize], sorted: &[usize], k: usize) -> Option<Tie> {
+    if k == 0 || k > sorted.len() {
+        return None;
+    }
+
+    let calories = sorted[sorted.len() - k];
+    let above = sorted.iter().filter(|&&total| total > calories).count();
+
+    Some(Tie {
+        k,
+        calories,
+        elves: totals
+            .iter()
+            .enumerate()
+            .filter(|&(_, &total)| total == calories)
+            .map(|(idx, _)| idx + 1)
+            .collect(),
+        in_top: k - above,
+    })
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Elves:     {}, carrying {} items",
+            self.elves, self.items
+        )?;
+        writeln!(f, "Total:     {}", self.total)?;
+        writeln!(f, "Min:       {}", self.min)?;
+        writeln!(f, "Max:       {}", self.max)?;
+        writeln!(f, "Mean:      {:.2}", self.mean)?;
+        writeln!(f, "Median:    {:.2}", self.median)?;
+        writeln!(f, "Std dev:   {:.2}", self.std_dev)?;
+
+        for Percentile { percent, calories } in &self.percentiles {
+            writeln!(f, "{:<11}{calories:.2}", format!("p{percent}:"))?;
+        }
+
+        let Item { elf, calories } = self.largest_item;
+        writeln!(f, "Largest item: {calories} calories, carried by elf {elf}")?;
+
+        if let Some(Tie {
+            k,
+            calories,
+            elves,
+            in_top,
+        }) = &self.kth
+        {
+            let carried_by = match &elves[..] {
+                [elf] => format!("elf {elf}"),
+                elves => {
+                    let elves: Vec<String> = elves.iter().map(|elf| elf.to_string()).collect();
+                    format!("elves {}", elves.join(", "))
+                }
+            };
+
+            writeln!(
+                f,
+                "Position {k}: {calories} calories, carried by {carried_by} ({in_top} in the top {k})"
+            )?;
+        }
+
+        writeln!(f, "Histogram:")?;
+
+        let widest = self
+            .histogram
+            .iter()
+            .map(|bucket| bucket.elves)
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        let digits = self.max.to_string().len();
+
+        for Bucket { from, to, elves } in &self.histogram {
+            // bars of at most 40 characters
+            let bar = "#".repeat(elves * 40 / widest);
+            writeln!(f, "  {from:>digits$} - {to:>digits$} | {bar} {elves}")?;
+        }
+
+        Ok(())
+    }
+}
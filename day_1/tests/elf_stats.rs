@@ -0,0 +1,98 @@
+use day_1::{stats::Report, Elf};
+use utils::Solver;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+fn report(input: &str, k: usize, buckets: usize) -> Report {
+    Report::new(&Elf::parse(input).unwrap(), k, buckets).unwrap()
+}
+
+#[test]
+fn report_summarizes_the_totals() {
+    let report = report(EXAMPLE, 3, 4);
+
+    assert_eq!((report.elves, report.items, report.total), (5, 10, 55000));
+    assert_eq!((report.min, report.max), (4000, 24000));
+    assert_eq!(report.mean, 11000.0);
+    assert_eq!(report.median, 10000.0);
+    assert!((report.std_dev - 6985.7).abs() < 0.01, "{}", report.std_dev);
+
+    let p90 = report.percentiles.iter().find(|p| p.percent == 90).unwrap();
+    assert!((p90.calories - 18800.0).abs() < 1e-9);
+
+    assert_eq!(
+        (report.largest_item.elf, report.largest_item.calories),
+        (5, 10000)
+    );
+
+    let buckets: Vec<_> = report
+        .histogram
+        .iter()
+        .map(|bucket| (bucket.from, bucket.to, bucket.elves))
+        .collect();
+    assert_eq!(
+        buckets,
+        [
+            (4000, 9000, 2),
+            (9000, 14000, 2),
+            (14000, 19000, 0),
+            (19000, 24000, 1)
+        ]
+    );
+}
+
+#[test]
+fn totals_that_overflow_are_errors() {
+    let elves = Elf::parse(&format!("{}\n\n1\n", usize::MAX)).unwrap();
+    let err = Report::new(&elves, 1, 1).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The elves carry more calories than fit into usize"
+    );
+}
+
+#[test]
+fn ties_at_the_kth_position_are_reported() {
+    let report = report("1\n\n2\n\n3\n\n2\n", 2, 10);
+
+    let tie = report.kth.unwrap();
+    assert_eq!(tie.calories, 2);
+    assert_eq!(tie.elves, [2, 4]);
+    assert_eq!(tie.in_top, 1);
+
+    // the histogram does not go past the largest total
+    assert_eq!(report.histogram.len(), 3);
+
+    // nor has more buckets than there are totals
+    let report = self::report("0\n\n18446744073709551615\n", 1, usize::MAX);
+    let buckets: Vec<_> = report
+        .histogram
+        .iter()
+        .map(|bucket| (bucket.from, bucket.to, bucket.elves))
+        .collect();
+    assert_eq!(
+        buckets,
+        [
+            (0, 9223372036854775808, 1),
+            (9223372036854775808, usize::MAX, 1)
+        ]
+    );
+
+    assert!(self::report("1\n", 2, 10).kth.is_none());
+}
+
+#[test]
+fn report_is_printed_as_text_and_json() {
+    let report = report(EXAMPLE, 3, 4);
+
+    let text = report.to_string();
+    assert!(text.contains("Median:    10000.00"), "{text}");
+    assert!(text.contains("Position 3: 10000 calories, carried by elf 5 (1 in the top 3)"));
+    assert!(text.contains("  19000 - 24000 | #################### 1"));
+
+    let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["kth"]["elves"], serde_json::json!([5]));
+    assert_eq!(json["histogram"][0]["elves"], 2);
+
+    assert!(Report::new(&[], 3, 4).is_err());
+}
@@ -0,0 +1,126 @@
+use day_1::{
+    inventory::{self, Mode},
+    Elf, Params,
+};
+use utils::{ParseError, Solver};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+/// The context, line and what was expected for the first problem with `input` in strict mode.
+fn strict_error(input: &str) -> (String, usize, String) {
+    let err = inventory::parse(input, Mode::Strict).unwrap_err();
+    let parse_error = err.downcast_ref::<ParseError>().unwrap();
+
+    (
+        err.to_string(),
+        parse_error.line().unwrap(),
+        parse_error.expected().to_string(),
+    )
+}
+
+#[test]
+fn strict_mode_accepts_the_puzzle_format() {
+    let inventory = inventory::parse(EXAMPLE, Mode::Strict).unwrap();
+    assert_eq!(inventory.elves.len(), 5);
+    assert!(inventory.warnings.is_empty());
+
+    // like line breaks on Windows
+    assert!(inventory::parse("1\r\n", Mode::Strict).is_ok());
+    let crlf = EXAMPLE.replace('\n', "\r\n");
+    assert_eq!(
+        inventory::parse(&crlf, Mode::Strict).unwrap().elves,
+        inventory.elves
+    );
+
+    // the final line break is optional
+    let elves = inventory::parse(EXAMPLE.trim_end(), Mode::Strict)
+        .unwrap()
+        .elves;
+    assert_eq!(elves, inventory.elves);
+    assert_eq!(Elf::part2(&elves, &Params::default()).unwrap(), 45000);
+}
+
+#[test]
+fn strict_mode_reports_the_elf_and_line() {
+    let cases = [
+        (
+            "1\n\n\n2\n",
+            2,
+            3,
+            "the calories of an item (inventories are separated by a single blank line)",
+        ),
+        ("\n1\n", 1, 1, "the calories of an item"),
+        ("1\n2 \n", 1, 2, "end of line"),
+        ("1 \r\n", 1, 1, "end of line"),
+        ("1\n\n-3\n", 2, 3, "calories that are not negative"),
+        ("1\n\n+3\n", 2, 3, "the calories of an item"),
+        (
+            "1\n\n2\n\n99999999999999999999999\n",
+            3,
+            5,
+            "calories that fit into usize",
+        ),
+        (
+            "18446744073709551615\n1\n",
+            1,
+            2,
+            "calories that add up to a total fitting into usize",
+        ),
+    ];
+
+    for (input, elf, line, expected) in cases {
+        assert_eq!(
+            strict_error(input),
+            (
+                format!("Invalid inventory of elf {elf}"),
+                line,
+                expected.to_string()
+            ),
+            "{input:?}"
+        );
+    }
+
+    let err = Elf::parse("1\n\nthree\n").unwrap_err();
+    assert!(format!("{err:#}").starts_with(
+        "Invalid inventory of elf 2: expected the calories of an item, found `three` at line 3"
+    ));
+}
+
+#[test]
+fn lenient_mode_skips_bad_lines_with_warnings() {
+    let input = "\n1000\n2000 \n\n\n-5\nabc\n\n3000\n";
+    let inventory = inventory::parse(input, Mode::Lenient).unwrap();
+
+    let warnings: Vec<_> = inventory
+        .warnings
+        .iter()
+        .map(|warning| {
+            let line = warning.downcast_ref::<ParseError>().unwrap().line();
+            (warning.to_string(), line.unwrap())
+        })
+        .collect();
+    let elf = |elf| format!("Invalid inventory of elf {elf}");
+    assert_eq!(
+        warnings,
+        [
+            (elf(1), 1),
+            (elf(1), 3),
+            (elf(2), 5),
+            (elf(2), 6),
+            (elf(2), 7)
+        ]
+    );
+
+    // the second elf has no valid items left, and keeps its place
+    assert_eq!(inventory.elves.len(), 3);
+    let params = Params { top: 3 };
+    assert_eq!(Elf::part1(&inventory.elves, &params).unwrap(), 3000);
+    assert_eq!(Elf::part2(&inventory.elves, &params).unwrap(), 6000);
+
+    let clean = inventory::parse(EXAMPLE, Mode::Lenient).unwrap();
+    assert!(clean.warnings.is_empty());
+    assert_eq!(
+        clean.elves,
+        inventory::parse(EXAMPLE, Mode::Strict).unwrap().elves
+    );
+}
@@ -0,0 +1,77 @@
+use std::{fs::File, io::BufReader};
+
+use day_1::{top_elves_in, Inventories, Ranked, TopElves};
+use utils::{day_input_path, workspace_root, ParseError};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn top_elves_are_reported_by_position() {
+    let top = top_elves_in(EXAMPLE.as_bytes(), 3).unwrap();
+    assert_eq!(top.total(), 45000);
+
+    let ranking = top.into_ranking();
+    assert_eq!(
+        ranking,
+        [
+            Ranked {
+                elf: 4,
+                calories: 24000
+            },
+            Ranked {
+                elf: 3,
+                calories: 11000
+            },
+            Ranked {
+                elf: 5,
+                calories: 10000
+            },
+        ]
+    );
+}
+
+#[test]
+fn any_number_of_elves_can_be_asked_for() {
+    let elves = |top: TopElves| -> Vec<usize> {
+        top.into_ranking()
+            .into_iter()
+            .map(|ranked| ranked.elf)
+            .collect()
+    };
+
+    assert!(elves(TopElves::of([3, 1, 2], 0)).is_empty());
+    assert_eq!(elves(TopElves::of([3, 1, 2], 10)), [1, 3, 2]);
+
+    // the earlier of elves carrying as much is ranked higher
+    assert_eq!(elves(TopElves::of([5, 7, 3, 7, 7], 2)), [2, 4]);
+}
+
+#[test]
+fn inventories_are_read_from_any_reader() {
+    let path = day_input_path(&workspace_root(), 1);
+    let top = top_elves_in(BufReader::new(File::open(path).unwrap()), 3).unwrap();
+
+    assert_eq!(top.total(), 200044);
+    assert_eq!(top.into_ranking()[0].calories, 68923);
+
+    // the last inventory does not need a line break
+    let calories: Vec<usize> = Inventories::new("1\n2\r\n\n3".as_bytes())
+        .map(|elf| elf.unwrap().cal_count())
+        .collect();
+    assert_eq!(calories, [3, 3]);
+}
+
+#[test]
+fn invalid_inventories_are_refused_with_their_line() {
+    let err = top_elves_in("1\n\n\n2\n".as_bytes(), 1).unwrap_err();
+    let line = err.downcast_ref::<ParseError>().unwrap().line();
+    assert_eq!(line, Some(3), "{err:#}");
+
+    let err = top_elves_in("1\n2\nthree\n".as_bytes(), 1).unwrap_err();
+    assert!(format!("{err:#}").contains("found `three`"), "{err:#}");
+
+    let overflowing = format!("{}\n1\n", usize::MAX);
+    let mut inventories = Inventories::new(overflowing.as_bytes());
+    assert!(inventories.next().unwrap().is_err());
+    assert!(inventories.next().is_none());
+}
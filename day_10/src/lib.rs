@@ -0,0 +1,281 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use utils::{Color, Cursor, Frame, FromCursor, ParseError, Recorder, Solver};
+
+use anyhow::{anyhow, ensure, Result};
+use serde::Deserialize;
+use tracing::trace;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Instruction {
+    AddX(isize),
+    Noop,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Instruction {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        if cursor.eat("noop") {
+            Ok(Self::Noop)
+        } else if cursor.eat("addx ") {
+            Ok(Self::AddX(cursor.signed()?))
+        } else {
+            Err(cursor.error("`noop` or `addx`"))
+        }
+    }
+}
+
+trait CycleLen {
+    fn cycle_len(&self) -> usize;
+}
+
+impl CycleLen for Instruction {
+    fn cycle_len(&self) -> usize {
+        use Instruction::*;
+        match self {
+            AddX(_) => 2,
+            Noop => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExecutingInstruction {
+    instruction: Instruction,
+    cycles_left: usize,
+}
+
+impl From<Instruction> for ExecutingInstruction {
+    fn from(instruction: Instruction) -> Self {
+        Self {
+            instruction,
+            cycles_left: instruction.cycle_len(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Registers {
+    x: isize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cpu {
+    registers: Registers,
+    cycle: usize,
+    executing: Option<ExecutingInstruction>,
+    instructions: VecDeque<Instruction>,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Self {
+            registers: Registers { x: 1 },
+            cycle: 1,
+            executing: None,
+            instructions: VecDeque::default(),
+        }
+    }
+
+    fn load(&mut self, instructions: &[Instruction]) {
+        self.instructions.extend(instructions.iter());
+    }
+
+    fn value_at(&mut self, cycle: usize) -> Result<isize> {
+        while self.cycle < cycle {
+            self.execute()?;
+        }
+
+        Ok(self.registers.x)
+    }
+
+    fn execute(&mut self) -> Result<()> {
+        self.cycle += 1;
+
+        if self.executing.is_none() {
+            self.executing = match self.instructions.pop_front() {
+                Some(instr) => Some(ExecutingInstruction::from(instr)),
+                None => return Err(anyhow!("No instructions left to execute.")),
+            }
+        }
+
+        if let Some(ref mut exec) = self.executing {
+            exec.cycles_left -= 1;
+
+            if exec.cycles_left == 0 {
+                let instr = exec.instruction;
+                self.apply(instr);
+                self.executing = None;
+
+                trace!(cycle = self.cycle, ?instr, x = self.registers.x, "executed");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply(&mut self, instr: Instruction) {
+        match instr {
+            Instruction::AddX(value) => self.registers.x += value,
+            Instruction::Noop => {}
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.executing.is_none() && self.instructions.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Crt {
+    cpu: Cpu,
+    line: usize,
+    width: usize,
+}
+
+impl Crt {
+    fn new(cpu: Cpu, width: usize) -> Self {
+        Self {
+            cpu,
+            line: 0,
+            width,
+        }
+    }
+
+    fn load(&mut self, instructions: &[Instruction]) {
+        self.cpu.load(instructions)
+    }
+
+    /// A screen `width` pixels wide, driven by a new cpu running `instructions`.
+    fn with_program(instructions: &[Instruction], width: usize) -> Result<Self> {
+        ensure!(width > 0, "The screen needs to be at least one pixel wide");
+
+        let mut crt = Crt::new(Cpu::new(), width);
+        crt.load(instructions);
+
+        Ok(crt)
+    }
+
+    /// Draws the screen line by line, emitting it into `recorder` after every pixel.
+    fn draw(&mut self, mut recorder: Option<&mut Recorder>) -> Result<String> {
+        let mut screen = String::new();
+
+        while !self.cpu.is_empty() {
+            let res = self.draw_line(&mut screen, recorder.as_deref_mut());
+            screen.push('\n');
+
+            if res.is_err() {
+                break;
+            }
+        }
+
+        if let Some(recorder) = recorder {
+            recorder.finish_with(|| screen_frame(&screen));
+        }
+
+        Ok(screen)
+    }
+
+    fn draw_line(
+        &mut self,
+        screen: &mut String,
+        mut recorder: Option<&mut Recorder>,
+    ) -> Result<()> {
+        for pixel in 0..self.width {
+            let cycle = self.line * self.width + pixel + 1;
+            let x = self.cpu.value_at(cycle)?;
+            let range = (x - 1)..=(x + 1);
+
+            let pixel = match range.contains(&(pixel as isize)) {
+                true => "#",
+                false => ".",
+            };
+
+            screen.push_str(pixel);
+
+            if let Some(recorder) = recorder.as_deref_mut() {
+                recorder.record_with(|| screen_frame(screen));
+            }
+        }
+
+        self.line += 1;
+        Ok(())
+    }
+}
+
+fn screen_frame(screen: &str) -> Frame {
+    Frame::from_text(screen, |ch| match ch {
+        '#' => Color::GREEN,
+        _ => Color::DARK_GREY,
+    })
+}
+
+/// The cycles whose signal strengths part 1 adds up, and the width of the screen.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub cycles: Vec<usize>,
+    pub width: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            cycles: vec![20, 60, 100, 140, 180, 220],
+            width: 40,
+        }
+    }
+}
+
+impl Solver for Cpu {
+    const DAY: u8 = 10;
+
+    type Parsed = Vec<Instruction>;
+    type Answer1 = isize;
+    type Answer2 = String;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(utils::parse_lines(input)?)
+    }
+
+    fn part1(instructions: &Self::Parsed, params: &Params) -> Result<isize> {
+        let mut cpu = Cpu::new();
+        cpu.load(instructions);
+
+        // the cpu only runs forwards
+        let mut cycles = params.cycles.clone();
+        cycles.sort();
+
+        let mut res = 0;
+        for cycle in cycles {
+            let val = cpu.value_at(cycle)?;
+            res += val * cycle as isize;
+        }
+
+        Ok(res)
+    }
+
+    fn part2(instructions: &Self::Parsed, params: &Params) -> Result<String> {
+        let screen = Crt::with_program(instructions, params.width)?.draw(None)?;
+        Ok(screen.trim_end().to_string())
+    }
+
+    // the screen of part 2 lighting up pixel by pixel
+    fn animate(
+        instructions: &Self::Parsed,
+        params: &Params,
+        recorder: &mut Recorder,
+    ) -> Result<()> {
+        Crt::with_program(instructions, params.width)?.draw(Some(recorder))?;
+
+        Ok(())
+    }
+}
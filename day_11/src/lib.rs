@@ -0,0 +1,324 @@
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+};
+
+use anyhow::{ensure, Result};
+use serde::Deserialize;
+use tracing::trace;
+use utils::{Cursor, FromCursor, ParseError, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Num {
+    Identity,
+    Value(usize),
+}
+
+impl FromStr for Num {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Num {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        if cursor.eat("old") {
+            return Ok(Self::Identity);
+        }
+
+        match cursor.peek() {
+            Some(ch) if ch.is_ascii_digit() => Ok(Self::Value(cursor.unsigned()?)),
+            _ => Err(cursor.error("`old` or a number")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Operation {
+    kind: OperationKind,
+    first: Num,
+    second: Num,
+}
+
+impl Operation {
+    fn apply(&self, input: usize) -> usize {
+        let first = match self.first {
+            Num::Identity => input,
+            Num::Value(val) => val,
+        };
+
+        let second = match self.second {
+            Num::Identity => input,
+            Num::Value(val) => val,
+        };
+
+        self.kind.apply(first, second)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Test {
+    divisor: usize,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Test {
+    fn get_monkey_idx(&self, input: usize) -> usize {
+        match input.is_multiple_of(self.divisor) {
+            true => self.if_true,
+            false => self.if_false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Monkey {
+    items: VecDeque<usize>,
+    operation: Operation,
+    test: Test,
+    inspections: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OperationKind {
+    Add,
+    Mul,
+}
+
+impl FromStr for OperationKind {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for OperationKind {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        use OperationKind::*;
+        let op = match cursor.satisfy("operator `+` or `*`", |ch| ch == '+' || ch == '*')? {
+            '+' => Add,
+            _ => Mul,
+        };
+
+        Ok(op)
+    }
+}
+
+impl OperationKind {
+    fn apply<T>(&self, a: T, b: T) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Debug,
+    {
+        match self {
+            OperationKind::Add => a + b,
+            OperationKind::Mul => a * b,
+        }
+    }
+}
+
+impl FromCursor for Operation {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        // input looks like:
+        // old * 19
+        let first = cursor.parse()?;
+        cursor.literal(" ")?;
+        let kind = cursor.parse()?;
+        cursor.literal(" ")?;
+        let second = cursor.parse()?;
+
+        Ok(Operation {
+            kind,
+            first,
+            second,
+        })
+    }
+}
+
+impl FromCursor for Test {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        cursor.literal("  Test: divisible by ")?;
+        let rest = cursor.rest();
+        let divisor = cursor.unsigned()?;
+        if divisor == 0 {
+            let digits = &rest[..rest.len() - cursor.rest().len()];
+            return Err(cursor.error_at(digits, "a divisor other than 0"));
+        }
+        cursor.line_end()?;
+
+        cursor.literal("    If true: throw to monkey ")?;
+        let if_true = cursor.unsigned()?;
+        cursor.line_end()?;
+
+        cursor.literal("    If false: throw to monkey ")?;
+        let if_false = cursor.unsigned()?;
+
+        Ok(Test {
+            divisor,
+            if_true,
+            if_false,
+        })
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Monkey {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        cursor.literal("Monkey ")?;
+        let _index: usize = cursor.unsigned()?;
+        cursor.literal(":")?;
+        cursor.line_end()?;
+
+        cursor.literal("  Starting items: ")?;
+        let items = cursor.separated(", ", |cursor| cursor.unsigned())?;
+        cursor.line_end()?;
+
+        cursor.literal("  Operation: new = ")?;
+        let operation = cursor.parse()?;
+        cursor.line_end()?;
+
+        let test = cursor.parse()?;
+        cursor.line_end()?;
+
+        Ok(Monkey {
+            items: items.into(),
+            operation,
+            test,
+            inspections: 0,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    monkeys: Vec<Monkey>,
+    divisor: usize,
+    relief: bool,
+}
+
+impl Game {
+    fn new(monkeys: Vec<Monkey>, relief: bool) -> Self {
+        let divisor = monkeys.iter().map(|monkey| monkey.test.divisor).product();
+
+        Self {
+            monkeys,
+            divisor,
+            relief,
+        }
+    }
+
+    fn simulate(&mut self, count: usize) {
+        for round in 1..=count {
+            for monkey in 0..self.monkeys.len() {
+                self.simulate_monkey(monkey);
+            }
+
+            trace!(round, inspections = ?self.inspections(), "round");
+        }
+    }
+
+    fn simulate_monkey(&mut self, monkey_idx: usize) {
+        let monkey = &mut self.monkeys[monkey_idx];
+        let mut send = Vec::default();
+
+        while let Some(item) = monkey.items.pop_front() {
+            monkey.inspections += 1;
+            let worry = match self.relief {
+                // part 1: worry level is divided by three after each inspection
+                true => monkey.operation.apply(item) / 3,
+                false => monkey.operation.apply(item) % self.divisor,
+            };
+            let to_monkey = monkey.test.get_monkey_idx(worry);
+
+            trace!(from = monkey_idx, to = to_monkey, worry, "throw");
+            send.push((to_monkey, worry));
+        }
+
+        for (to_monkey, item) in send {
+            self.monkeys[to_monkey].items.push_back(item);
+        }
+    }
+
+    fn inspections(&self) -> Vec<usize> {
+        self.monkeys
+            .iter()
+            .map(|monkey| monkey.inspections)
+            .collect()
+    }
+
+    fn most_active(&self, count: usize) -> Vec<usize> {
+        let mut inspections = self.inspections();
+
+        inspections.sort();
+        inspections.iter().rev().take(count).copied().collect()
+    }
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: bool) -> usize {
+    let mut game = Game::new(monkeys.to_vec(), relief);
+    game.simulate(rounds);
+
+    game.most_active(2).iter().product()
+}
+
+/// Number of rounds played in each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1_rounds: usize,
+    pub part2_rounds: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part1_rounds: 20,
+            part2_rounds: 10_000,
+        }
+    }
+}
+
+impl Solver for Game {
+    const DAY: u8 = 11;
+
+    type Parsed = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let monkeys = Cursor::new(input).blocks(Monkey::from_cursor)?;
+
+        for (idx, monkey) in monkeys.iter().enumerate() {
+            for target in [monkey.test.if_true, monkey.test.if_false] {
+                ensure!(
+                    target < monkeys.len(),
+                    "Monkey {idx} throws to monkey {target}, but there are only {} monkeys",
+                    monkeys.len()
+                );
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Parsed, params: &Params) -> Result<usize> {
+        Ok(monkey_business(monkeys, params.part1_rounds, true))
+    }
+
+    fn part2(monkeys: &Self::Parsed, params: &Params) -> Result<usize> {
+        Ok(monkey_business(monkeys, params.part2_rounds, false))
+    }
+}
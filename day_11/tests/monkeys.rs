@@ -0,0 +1,39 @@
+use day_11::{Game, Params};
+use utils::{ParseError, Solver};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+const SINGLE: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+
+#[test]
+fn monkeys_play_like_the_puzzle() {
+    let monkeys = Game::parse(EXAMPLE).unwrap();
+    let params = Params::default();
+
+    assert_eq!(Game::part1(&monkeys, &params).unwrap(), 10605);
+    assert_eq!(Game::part2(&monkeys, &params).unwrap(), 2713310158);
+    assert!(Game::parse(SINGLE).is_ok());
+}
+
+#[test]
+fn monkeys_that_cannot_play_are_errors() {
+    let err = Game::parse(&SINGLE.replace("false: throw to monkey 0", "false: throw to monkey 7"))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Monkey 0 throws to monkey 7, but there are only 1 monkeys"
+    );
+
+    let err = Game::parse(&SINGLE.replace("divisible by 23", "divisible by 0")).unwrap_err();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+    assert_eq!(
+        (err.line(), err.expected()),
+        (Some(4), "a divisor other than 0")
+    );
+}
@@ -0,0 +1,157 @@
+use anyhow::{anyhow, Result};
+use utils::{
+    search, Cells, Color, Cursor, Frame, FromCursor, Grid, ParseError, Pixel, Point, Recorder,
+    Solver,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Height {
+    Start(usize),
+    End(usize),
+    OnPath(usize),
+}
+
+impl Height {
+    fn into_inner(self) -> usize {
+        match self {
+            Height::Start(val) | Height::End(val) | Height::OnPath(val) => val,
+        }
+    }
+
+    fn reaches(&self, other: &Self) -> bool {
+        let this_height = self.into_inner();
+        let other_height = other.into_inner();
+
+        this_height > other_height || matches!(this_height.abs_diff(other_height), 0..=1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Node {
+    height: Height,
+    pos: Point,
+}
+
+impl FromCursor for Height {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let ch = cursor.satisfy("height `a`-`z`, start `S` or end `E`", |ch| {
+            matches!(ch, 'a'..='z' | 'S' | 'E')
+        })?;
+
+        let val = match ch {
+            'S' => 'a',
+            'E' => 'z',
+            _ => ch,
+        };
+
+        let val = val as usize - 'a' as usize;
+
+        let height = match ch {
+            'S' => Height::Start(val),
+            'E' => Height::End(val),
+            _ => Height::OnPath(val),
+        };
+
+        Ok(height)
+    }
+}
+
+impl Node {
+    /// Nodes reachable in one step: neighbours at most one higher.
+    fn neighbours<'a>(&self, heights: &'a Grid<Height>) -> impl Iterator<Item = Node> + 'a {
+        let height = self.height;
+
+        heights
+            .neighbours4(self.pos)
+            .filter(move |(_, other)| height.reaches(other))
+            .map(|(pos, &height)| Node { height, pos })
+    }
+}
+
+/// Draws the map shaded from low to high, with the steps taken so far highlighted.
+fn frame(heights: &Grid<Height>, steps: &[Node]) -> Frame {
+    let mut frame = Frame::of(heights, |_, height| {
+        let Some(&height) = height else {
+            return Pixel::BLANK;
+        };
+
+        let ch = match height {
+            Height::Start(_) => 'S',
+            Height::End(_) => 'E',
+            Height::OnPath(val) => (b'a' + val as u8) as char,
+        };
+        let shade = Color::DARK_GREEN.blend(Color::WHITE, height.into_inner() as f64 / 25.0);
+
+        Pixel::new(ch, shade)
+    });
+
+    for node in steps {
+        if let Some(&Pixel { ch, .. }) = frame.get(node.pos) {
+            frame.set(node.pos, Pixel::new(ch, Color::RED));
+        }
+    }
+
+    frame
+}
+
+fn nodes(heights: &Grid<Height>) -> impl Iterator<Item = Node> + '_ {
+    heights.iter().map(|(pos, &height)| Node { height, pos })
+}
+
+/// Shortest path from S to E.
+fn climb(heights: &Grid<Height>) -> Result<search::Path<Node>> {
+    let start = nodes(heights)
+        .find(|node| matches!(node.height, Height::Start(_)))
+        .ok_or_else(|| anyhow!("Missing start position"))?;
+
+    search::bfs(
+        start,
+        |node| node.neighbours(heights),
+        |node| matches!(node.height, Height::End(_)),
+    )
+    .ok_or_else(|| anyhow!("End is not reachable from start"))
+}
+
+impl Solver for Node {
+    const DAY: u8 = 12;
+
+    type Parsed = Grid<Height>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(utils::parse_str(input)?)
+    }
+
+    // part 1 - from S to E
+    fn part1(heights: &Self::Parsed, _: &()) -> Result<usize> {
+        Ok(climb(heights)?.steps())
+    }
+
+    // part 2 - from any a to E, choose shortest one
+    fn part2(heights: &Self::Parsed, _: &()) -> Result<usize> {
+        let starts = nodes(heights).filter(|node| node.height.into_inner() == 0);
+
+        let path = search::bfs_multi(
+            starts,
+            |node| node.neighbours(heights),
+            |node| matches!(node.height, Height::End(_)),
+        );
+
+        path.map(|path| path.steps())
+            .ok_or_else(|| anyhow!("End is not reachable from any lowest position"))
+    }
+
+    // the path of part 1 walked step by step
+    fn animate(heights: &Self::Parsed, _: &(), recorder: &mut Recorder) -> Result<()> {
+        let path = climb(heights)?;
+
+        for walked in 1..=path.nodes.len() {
+            recorder.record_with(|| frame(heights, &path.nodes[..walked]));
+        }
+        recorder.finish_with(|| frame(heights, &path.nodes));
+
+        Ok(())
+    }
+}
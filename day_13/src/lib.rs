@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+use utils::{Cursor, FromCursor, ParseError, Solver};
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Term(u8),
+    List(Vec<Packet>),
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Packet::*;
+
+        match (self, other) {
+            (Term(lhs), Term(rhs)) => lhs.cmp(rhs),
+            (List(lhs), List(rhs)) => lhs.cmp(rhs),
+            (Term(lhs), List(rhs)) => [Term(*lhs)][..].cmp(rhs),
+            (List(lhs), Term(rhs)) => lhs.as_slice().cmp(&[Term(*rhs)][..]),
+        }
+    }
+}
+
+impl FromCursor for Packet {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        if !cursor.eat("[") {
+            return match cursor.peek() {
+                Some(ch) if ch.is_ascii_digit() => Ok(Packet::Term(cursor.unsigned()?)),
+                _ => Err(cursor.error("`[` or a number")),
+            };
+        }
+
+        if cursor.eat("]") {
+            return Ok(Packet::List(Vec::new()));
+        }
+
+        let items = cursor.separated(",", Packet::from_cursor)?;
+        cursor.literal("]")?;
+
+        Ok(Packet::List(items))
+    }
+}
+
+impl Solver for Packet {
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<Packet>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let pairs = Cursor::new(input).blocks(|cursor| cursor.lines(Packet::from_cursor))?;
+
+        Ok(pairs.into_iter().flatten().collect())
+    }
+
+    fn part1(packets: &Self::Parsed, _: &()) -> Result<usize> {
+        let res1 = packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, window)| window[0] < window[1])
+            .map(|(idx, _)| idx + 1)
+            .sum();
+
+        Ok(res1)
+    }
+
+    fn part2(packets: &Self::Parsed, _: &()) -> Result<usize> {
+        let divider_2: Packet = utils::parse_str("[[2]]")?;
+        let divider_6: Packet = utils::parse_str("[[6]]")?;
+
+        let mut items = packets.clone();
+        items.extend_from_slice(&[divider_2.clone(), divider_6.clone()]);
+
+        items.sort();
+
+        let res2 = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| *item == &divider_2 || *item == &divider_6)
+            .map(|(idx, _)| idx + 1)
+            .product();
+
+        Ok(res2)
+    }
+}
@@ -0,0 +1,285 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use anyhow::Result;
+use serde::Deserialize;
+use tracing::trace;
+use utils::{
+    Bounds, Cells, Color, Cursor, Direction8, Frame, FromCursor, IntervalSet, ParseError, Point,
+    Recorder, Render, Solver, SparseGrid,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Material {
+    Rock,
+    Sand,
+}
+
+/// Where the sand pours into the cave, unless the parameters say otherwise.
+const SOURCE: Point = Point { x: 500, y: 0 };
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    cells: SparseGrid<Material, usize>,
+    sand: Sand,
+    source: Point,
+    // bounds of the rock
+    rock: Bounds,
+    resting: usize,
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Cave {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let mut cells = SparseGrid::new();
+
+        let paths = cursor.lines(|cursor| cursor.separated(" -> ", Point::from_cursor))?;
+
+        for points in paths {
+            for points in points.windows(2) {
+                if let &[point1, point2, ..] = points {
+                    set_rock(&mut cells, point1, point2);
+                }
+            }
+        }
+
+        let Some(rock) = cells.bounds() else {
+            return Err(cursor.error("at least one rock path like `498,4 -> 498,6`"));
+        };
+
+        Ok(Cave {
+            cells,
+            sand: Default::default(),
+            source: SOURCE,
+            rock,
+            resting: 0,
+        })
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = self.bounds();
+
+        for point in bounds.points() {
+            if point.x == bounds.min.x && point.y != bounds.min.y {
+                f.write_str("\n")?;
+            }
+
+            if point == self.source {
+                f.write_str("|")?;
+            } else if self.is_rock(&point) {
+                f.write_str("#")?;
+            } else if self.is_sand(&point) {
+                f.write_str("o")?;
+            } else if point == self.sand.pos {
+                f.write_str("+")?;
+            } else {
+                f.write_str(".")?;
+            }
+        }
+
+        f.write_str("\n")
+    }
+}
+
+impl Render for Cave {
+    fn frame(&self) -> Frame {
+        Frame::from_text(&self.to_string(), |ch| match ch {
+            '#' => Color::BROWN,
+            'o' => Color::YELLOW,
+            '+' => Color::ORANGE,
+            '|' => Color::WHITE,
+            _ => Color::DARK_GREY,
+        })
+    }
+}
+
+fn set_rock(cells: &mut SparseGrid<Material, usize>, point1: Point, point2: Point) {
+    // paths are either horizontal or vertical, so this is a line
+    for point in Bounds::of_point(point1)
+        .union(Bounds::of_point(point2))
+        .points()
+    {
+        cells.insert(point, Material::Rock);
+    }
+}
+
+impl Cave {
+    /// Bounds of the rock, extended up to the row the sand falls from.
+    fn bounds(&self) -> Bounds {
+        self.rock.union(Bounds::of_point(self.source))
+    }
+
+    fn is_rock(&self, point: &Point) -> bool {
+        self.cells.get(*point) == Some(&Material::Rock)
+    }
+
+    fn set_sand(&mut self, point: Point) {
+        if self.cells.insert(point, Material::Sand).is_none() {
+            self.resting += 1;
+        }
+    }
+
+    fn is_sand(&self, point: &Point) -> bool {
+        self.cells.get(*point) == Some(&Material::Sand)
+    }
+
+    fn is_air(&self, point: &Point) -> bool {
+        !self.cells.contains(*point) && !self.is_rock(point)
+    }
+
+    fn out_of_bounds(&self, point: Point) -> bool {
+        !self.bounds().contains(point)
+    }
+
+    /// Pours sand until it falls out of the bounds or piles up to the source, emitting a frame
+    /// into `recorder` whenever a grain comes to rest.
+    fn simulate(&mut self, mut recorder: Option<&mut Recorder>) {
+        self.sand.reset(self.source);
+
+        loop {
+            if let Some(point) = self.sand.can_fall(self) {
+                self.sand.fall_to(point);
+                trace!(pos = ?self.sand.pos, "fall");
+
+                if self.out_of_bounds(self.sand.pos) {
+                    // escaped bounds, will keep going forever
+                    break;
+                }
+            } else {
+                self.set_sand(self.sand.pos);
+                trace!(pos = ?self.sand.pos, resting = self.resting, "rest\n{self}");
+
+                if let Some(recorder) = recorder.as_deref_mut() {
+                    recorder.record(self);
+                }
+
+                if self.sand.pos == self.source {
+                    // the sand piled up to the source, nothing more can fall
+                    break;
+                }
+
+                self.sand.reset(self.source);
+            }
+        }
+
+        if let Some(recorder) = recorder {
+            recorder.finish(self);
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Sand {
+    pos: Point,
+}
+
+impl Sand {
+    fn can_fall(&self, cave: &Cave) -> Option<Point> {
+        [
+            Direction8::Down,
+            Direction8::DownLeft,
+            Direction8::DownRight,
+        ]
+        .into_iter()
+        .filter_map(|direction| self.pos.step(direction))
+        .find(|point| cave.is_air(point))
+    }
+
+    fn fall_to(&mut self, point: Point) {
+        self.pos = point
+    }
+
+    fn reset(&mut self, source: Point) {
+        self.pos = source;
+    }
+}
+
+impl Cave {
+    fn resting_sand(&self, source: Point, recorder: Option<&mut Recorder>) -> usize {
+        let mut cave = Cave {
+            source,
+            ..self.clone()
+        };
+        cave.simulate(recorder);
+
+        cave.resting
+    }
+
+    /// With a floor, sand comes to rest on every cell it can reach. Row by row these are the
+    /// cells below or diagonally below sand that are not rock, so no grain has to be simulated.
+    fn resting_sand_on_floor(&self, source: Point) -> usize {
+        let mut rock: BTreeMap<usize, IntervalSet<usize>> = BTreeMap::new();
+        for (point, _) in self.cells.iter() {
+            rock.entry(point.y).or_default().insert(point.x..=point.x);
+        }
+
+        let floor_row = self.rock.max.y.max(source.y) + 2;
+
+        let mut sand = IntervalSet::from(source.x..=source.x);
+        let mut resting = sand.len();
+
+        for y in source.y + 1..floor_row {
+            sand = sand
+                .ranges()
+                .map(|range| range.start().saturating_sub(1)..=range.end() + 1)
+                .collect();
+
+            if let Some(rock) = rock.get(&y) {
+                sand = sand.difference(rock);
+            }
+
+            resting += sand.len();
+        }
+
+        resting
+    }
+}
+
+/// Where the sand pours in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub source: Point,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { source: SOURCE }
+    }
+}
+
+impl Solver for Cave {
+    const DAY: u8 = 14;
+
+    type Parsed = Cave;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(cave: &Self::Parsed, params: &Params) -> Result<usize> {
+        Ok(cave.resting_sand(params.source, None))
+    }
+
+    fn part2(cave: &Self::Parsed, params: &Params) -> Result<usize> {
+        Ok(cave.resting_sand_on_floor(params.source))
+    }
+
+    // the sand of part 1 coming to rest grain by grain
+    fn animate(cave: &Self::Parsed, params: &Params, recorder: &mut Recorder) -> Result<()> {
+        cave.resting_sand(params.source, Some(recorder));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, ensure, Result};
+use serde::Deserialize;
+use tracing::{debug, trace};
+use utils::{
+    Bounds, Cells, Color, Cursor, Frame, FromCursor, IntervalSet, ParseError, Pixel, Recorder,
+    Solver, SparseGrid,
+};
+
+type Point = utils::Point<isize>;
+
+/// Most cells drawn in a frame. The real sensors are millions of positions apart, only the example
+/// is small enough to draw.
+const MAX_FRAME_CELLS: usize = 1 << 20;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Beacon {
+    pos: Point,
+}
+
+impl FromStr for Beacon {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(input)
+    }
+}
+
+impl FromCursor for Beacon {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        cursor.literal("closest beacon is at ")?;
+
+        Ok(Self {
+            pos: cursor.parse()?,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Sensor {
+    pos: Point,
+    beacon: Beacon,
+    distance: usize,
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(input)
+    }
+}
+
+impl FromCursor for Sensor {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        // input looks like:
+        // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+        cursor.literal("Sensor at ")?;
+        let pos: Point = cursor.parse()?;
+        cursor.literal(": ")?;
+        let beacon: Beacon = cursor.parse()?;
+
+        let distance = pos.manhattan(beacon.pos);
+
+        Ok(Self {
+            pos,
+            beacon,
+            distance,
+        })
+    }
+}
+
+impl Sensor {
+    fn reaches_line(&self, line: isize) -> bool {
+        self.pos.y.abs_diff(line) <= self.distance()
+    }
+
+    fn distance(&self) -> usize {
+        self.distance
+    }
+
+    pub(crate) fn reaches_point(&self, p: Point) -> bool {
+        self.distance() >= self.pos.manhattan(p)
+    }
+
+    pub(crate) fn contains_unreachable(&self, area: Bounds<isize>) -> bool {
+        let largest_distance = area
+            .corners()
+            .into_iter()
+            .map(|corner| corner.manhattan(self.pos))
+            .max()
+            .unwrap();
+
+        largest_distance > self.distance()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Marker {
+    Sensor,
+    Beacon,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Grid {
+    sensors: Vec<Sensor>,
+    cells: SparseGrid<Marker>,
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sensors: Vec<Sensor> = utils::parse_lines(s)?;
+
+        let mut cells = SparseGrid::new();
+
+        for sensor in &sensors {
+            cells.insert(sensor.pos, Marker::Sensor);
+            cells.insert(sensor.beacon.pos, Marker::Beacon);
+        }
+
+        Ok(Self { sensors, cells })
+    }
+}
+
+impl std::fmt::Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let map = self.cells.render(|_, cell| match cell {
+            Some(Marker::Sensor) => 'S',
+            Some(Marker::Beacon) => 'B',
+            None => '.',
+        });
+
+        writeln!(f, "{map}")
+    }
+}
+
+impl Grid {
+    /// Draws `view` with the positions reached by the first `covering` sensors filled in, and
+    /// the distress beacon if it is known.
+    fn frame(&self, view: Bounds<isize>, covering: usize, distress: Option<Point>) -> Frame {
+        let sensors = &self.sensors[..covering];
+
+        Frame::from_fn(view, |point| match self.cells.get(point) {
+            _ if Some(point) == distress => Pixel::new('X', Color::RED),
+            Some(Marker::Sensor) => Pixel::new('S', Color::GREEN),
+            Some(Marker::Beacon) => Pixel::new('B', Color::BLUE),
+            None if sensors.iter().any(|sensor| sensor.reaches_point(point)) => {
+                Pixel::new('#', Color::DARK_GREY)
+            }
+            None => Pixel::new('.', Color::GREY),
+        })
+    }
+
+    /// Positions in row `line` that cannot hold a beacon: those within reach of a sensor, except
+    /// for the beacons already found.
+    fn calculate(&self, line: isize) -> IntervalSet<isize> {
+        let mut covered: IntervalSet<isize> = self
+            .sensors
+            .iter()
+            .filter(|sensor| sensor.reaches_line(line))
+            .map(|sensor| {
+                let x_distance = (sensor.distance() - sensor.pos.y.abs_diff(line)) as isize;
+
+                sensor.pos.x - x_distance..=sensor.pos.x + x_distance
+            })
+            .collect();
+
+        for (beacon, _) in self
+            .cells
+            .row(line)
+            .filter(|(_, marker)| **marker == Marker::Beacon)
+        {
+            covered.remove(beacon.x..=beacon.x);
+        }
+
+        covered
+    }
+
+    fn calculate_part2(&self, min: isize, max: isize) -> Result<Point> {
+        // find beacon not reachable by ANY sensor
+
+        let area = Bounds::new(Point::new(min, min), Point::new(max, max));
+
+        let mut quadrants_to_check = vec![area];
+
+        while let Some(area) = quadrants_to_check.pop() {
+            trace!(?area, "check");
+            let Bounds { min, max } = area;
+
+            if min == max {
+                if self.sensors.iter().all(|sensor| !sensor.reaches_point(min)) {
+                    debug!(beacon = ?min, "found");
+                    return Ok(min);
+                }
+            } else {
+                let mid = Point::new(min.x + (max.x - min.x) / 2, min.y + (max.y - min.y) / 2);
+
+                let quadrants = [
+                    Bounds::new(min, mid),
+                    Bounds::new(Point::new(mid.x + 1, min.y), Point::new(max.x, mid.y)),
+                    Bounds::new(Point::new(min.x, mid.y + 1), Point::new(mid.x, max.y)),
+                    Bounds::new(Point::new(mid.x + 1, mid.y + 1), max),
+                ];
+
+                for quadrant in quadrants {
+                    if quadrant.min.x > quadrant.max.x || quadrant.min.y > quadrant.max.y {
+                        continue;
+                    }
+
+                    if self
+                        .sensors
+                        .iter()
+                        .all(|sensor| sensor.contains_unreachable(quadrant))
+                    {
+                        quadrants_to_check.push(quadrant);
+                    }
+                }
+            }
+        }
+
+        bail!("No position within {min}..={max} is out of reach of every sensor");
+    }
+}
+
+/// The row to count the positions without a beacon in for part 1, and the largest coordinate
+/// the distress beacon can have in part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub row: isize,
+    pub bound: isize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            row: 2_000_000,
+            bound: 4_000_000,
+        }
+    }
+}
+
+impl Solver for Grid {
+    const DAY: u8 = 15;
+
+    type Parsed = Grid;
+    type Answer1 = usize;
+    type Answer2 = isize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(grid: &Self::Parsed, params: &Params) -> Result<usize> {
+        Ok(grid.calculate(params.row).len())
+    }
+
+    fn part2(grid: &Self::Parsed, params: &Params) -> Result<isize> {
+        let res2 = grid.calculate_part2(0, params.bound)?;
+
+        res2.x
+            .checked_mul(4000000)
+            .and_then(|x| x.checked_add(res2.y))
+            .ok_or_else(|| anyhow!("The tuning frequency of {res2} does not fit into isize"))
+    }
+
+    // the sensors covering the area one after the other, leaving the distress beacon of part 2
+    fn animate(grid: &Self::Parsed, params: &Params, recorder: &mut Recorder) -> Result<()> {
+        let mut view = grid
+            .cells
+            .bounds()
+            .ok_or_else(|| anyhow!("There are no sensors to draw"))?;
+
+        let distress = grid.calculate_part2(0, params.bound)?;
+        view.extend(distress);
+
+        ensure!(
+            view.width().saturating_mul(view.height()) <= MAX_FRAME_CELLS,
+            "The sensors are too far apart to draw, they span {}x{} positions",
+            view.width(),
+            view.height()
+        );
+
+        for covering in 0..=grid.sensors.len() {
+            recorder.record_with(|| grid.frame(view, covering, None));
+        }
+
+        let last = || grid.frame(view, grid.sensors.len(), Some(distress));
+        recorder.record_with(last);
+        recorder.finish_with(last);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use tracing::{debug, trace};
+use utils::{search, Cursor, FromCursor, ParseError, Solver};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Valve {
+    label: String,
+    rate: usize,
+    tunnels: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Valve {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        // input looks like:
+        // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+        cursor.literal("Valve ")?;
+        let label = cursor.word()?.to_string();
+        cursor.literal(" has flow ")?;
+        let rate = cursor.field("rate")?;
+
+        if !cursor.eat("; tunnels lead to valves ") {
+            cursor.literal("; tunnel leads to valve ")?;
+        }
+
+        let tunnels = cursor.separated(", ", |cursor| Ok(cursor.word()?.to_string()))?;
+
+        Ok(Valve {
+            label,
+            rate,
+            tunnels,
+        })
+    }
+}
+
+/// The valves worth opening, with the minutes it takes to walk between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    rates: Vec<usize>,
+    // minutes from `from` to `to` at `distances[from][to]`, `None` if unreachable
+    distances: Vec<Vec<Option<usize>>>,
+    // minutes from every valve to each of the ones worth opening, to start from
+    entrances: BTreeMap<String, Vec<Option<usize>>>,
+}
+
+impl Network {
+    fn new(valves: &BTreeMap<String, Valve>) -> Result<Self> {
+        let all_pairs = search::floyd_warshall(valves.keys().cloned(), |label| {
+            valves[label]
+                .tunnels
+                .iter()
+                .map(|tunnel| (tunnel.clone(), 1))
+                .collect::<Vec<_>>()
+        });
+
+        // valves without flow are only ever walked through
+        let useful: Vec<&Valve> = valves.values().filter(|valve| valve.rate > 0).collect();
+
+        if useful.len() > 64 {
+            bail!(
+                "Too many valves with flow: {}, at most 64 are supported",
+                useful.len()
+            );
+        }
+
+        let entrances: BTreeMap<String, Vec<Option<usize>>> = valves
+            .keys()
+            .map(|from| {
+                let distances = useful
+                    .iter()
+                    .map(|to| all_pairs.cost(from, &to.label))
+                    .collect();
+
+                (from.clone(), distances)
+            })
+            .collect();
+
+        Ok(Self {
+            rates: useful.iter().map(|valve| valve.rate).collect(),
+            distances: useful
+                .iter()
+                .map(|valve| entrances[&valve.label].clone())
+                .collect(),
+            entrances,
+        })
+    }
+
+    /// Most pressure that can be released within `time` minutes from `start` for every set of
+    /// valves that can be opened in that time. Sets are bit masks of valve indices.
+    fn pressures(&self, start: &str, time: usize) -> Result<HashMap<u64, usize>> {
+        let Some(distances) = self.entrances.get(start) else {
+            bail!("There is no valve {start} to start from");
+        };
+
+        let mut best = HashMap::new();
+        self.open_next(distances, time, 0, 0, &mut best);
+        debug!(sets = best.len(), "pressures");
+
+        Ok(best)
+    }
+
+    /// `distances` are the minutes from the current valve to each valve worth opening.
+    fn open_next(
+        &self,
+        distances: &[Option<usize>],
+        time: usize,
+        opened: u64,
+        pressure: usize,
+        best: &mut HashMap<u64, usize>,
+    ) {
+        let known = best.entry(opened).or_default();
+        *known = pressure.max(*known);
+
+        for (next, &rate) in self.rates.iter().enumerate() {
+            if opened & 1 << next != 0 {
+                continue;
+            }
+
+            // walk there and spend a minute opening it
+            let Some(needed) = distances[next].map(|distance| distance + 1) else {
+                continue;
+            };
+
+            if needed < time {
+                let left = time - needed;
+                trace!(valve = next, left, pressure, "move");
+                self.open_next(
+                    &self.distances[next],
+                    left,
+                    opened | 1 << next,
+                    pressure + rate * left,
+                    best,
+                );
+            }
+        }
+    }
+}
+
+/// Minutes it takes to teach the elephant in part 2, before either of you opens a valve.
+const TEACHING_TIME: usize = 4;
+
+/// The valve everyone starts at and the minutes until the volcano erupts.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub start: String,
+    pub time_limit: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            start: "AA".to_string(),
+            time_limit: 30,
+        }
+    }
+}
+
+impl Solver for Valve {
+    const DAY: u8 = 16;
+
+    type Parsed = Network;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let valves: Vec<Valve> = utils::parse_lines(input)?;
+
+        let valves: BTreeMap<String, Valve> = valves
+            .into_iter()
+            .map(|valve: Valve| (valve.label.clone(), valve))
+            .collect();
+
+        Network::new(&valves)
+    }
+
+    fn part1(network: &Self::Parsed, params: &Params) -> Result<usize> {
+        let best = network.pressures(&params.start, params.time_limit)?;
+
+        Ok(best.into_values().max().unwrap_or_default())
+    }
+
+    // you and an elephant each open a different set of valves
+    fn part2(network: &Self::Parsed, params: &Params) -> Result<usize> {
+        let time = params.time_limit.saturating_sub(TEACHING_TIME);
+
+        let mut best: Vec<(u64, usize)> = network
+            .pressures(&params.start, time)?
+            .into_iter()
+            .collect();
+        best.sort_by_key(|&(_, pressure)| std::cmp::Reverse(pressure));
+
+        let mut res = 0;
+        for (idx, &(mine, pressure)) in best.iter().enumerate() {
+            // sorted by pressure, so no later pair can do better
+            if pressure * 2 < res {
+                break;
+            }
+
+            if let Some((_, other)) = best[idx..].iter().find(|(other, _)| mine & other == 0) {
+                res = res.max(pressure + other);
+            }
+        }
+
+        Ok(res)
+    }
+}
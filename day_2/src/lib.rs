@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use rules::Ruleset;
+use serde::Deserialize;
+use strategy::{Mapping, Strategy};
+use utils::{Cursor, FromCursor, ParseError, Solver};
+
+pub mod rules;
+pub mod strategy;
+
+/// How a round ends for me.
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// A line of the guide: their move and my response, as the letters that are looked up in the
+/// [`Ruleset`] when solving.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    their: char,
+    response: char,
+}
+
+impl FromCursor for Round {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let their = cursor.satisfy("an uppercase letter", |ch| ch.is_ascii_uppercase())?;
+        cursor.literal(" ")?;
+        let response = cursor.satisfy("an uppercase letter", |ch| ch.is_ascii_uppercase())?;
+
+        Ok(Round { their, response })
+    }
+}
+
+impl Round {
+    fn score(&self, rules: &Ruleset, strategy: &dyn Strategy) -> Result<usize> {
+        let their = rules.theirs(self.their)?;
+        let mine = strategy.choose(rules, their, self.response)?;
+        Ok(rules.calculate_score(mine, their))
+    }
+}
+
+/// My total score when following the guide with `strategy`.
+pub fn total_score(rounds: &[Round], rules: &Ruleset, strategy: &dyn Strategy) -> Result<usize> {
+    rounds
+        .iter()
+        .enumerate()
+        .try_fold(0, |score, (idx, round)| {
+            let round = round
+                .score(rules, strategy)
+                .with_context(|| format!("Invalid round on line {}", idx + 1))?;
+            Ok(score + round)
+        })
+}
+
+/// The rules of the game, and the strategy each part reads the guide with, by name: the built-in
+/// `moves` and `outcomes` of the puzzle, or one of `strategies`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1: String,
+    pub part2: String,
+    pub strategies: BTreeMap<String, Mapping>,
+    pub rules: Ruleset,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part1: "moves".to_string(),
+            part2: "outcomes".to_string(),
+            strategies: BTreeMap::new(),
+            rules: Ruleset::default(),
+        }
+    }
+}
+
+impl Solver for Round {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Round>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(utils::parse_lines(input)?)
+    }
+
+    fn part1(rounds: &Self::Parsed, params: &Params) -> Result<usize> {
+        let strategy = strategy::named(&params.part1, &params.strategies, &params.rules)?;
+        total_score(rounds, &params.rules, &*strategy)
+    }
+
+    fn part2(rounds: &Self::Parsed, params: &Params) -> Result<usize> {
+        let strategy = strategy::named(&params.part2, &params.strategies, &params.rules)?;
+        total_score(rounds, &params.rules, &*strategy)
+    }
+}
@@ -0,0 +1,219 @@
+//! The rules of the game: which moves there are, which of them beat which, and how rounds are
+//! scored. Rock paper scissors is the default, other games are given in the parameters:
+//!
+//! ```toml
+//! [day_2.rules]
+//! points = { loss = 0, draw = 3, win = 6 }
+//! moves = [
+//!     { name = "rock", score = 1, their = "A", mine = "X" },
+//!     { name = "paper", score = 2, their = "B", mine = "Y" },
+//!     { name = "scissors", score = 3, their = "C", mine = "Z" },
+//!     { name = "spock", score = 4, their = "D", mine = "V" },
+//!     { name = "lizard", score = 5, their = "E", mine = "W" },
+//! ]
+//! ```
+
+use anyhow::{anyhow, bail, ensure, Result};
+use serde::Deserialize;
+
+use crate::Outcome;
+
+/// A move, by its position in the [`Ruleset`] it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Choice(usize);
+
+/// A move of the game, and the letters it is written as in the first and second column of the
+/// guide.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Move {
+    pub name: String,
+    pub score: usize,
+    pub their: char,
+    pub mine: char,
+}
+
+/// Points for how a round ends, on top of the score of my move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Points {
+    pub loss: usize,
+    pub draw: usize,
+    pub win: usize,
+}
+
+impl Default for Points {
+    fn default() -> Self {
+        Self {
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+/// An odd number of moves, each beating the moves an odd number of places before it, going round
+/// to the end of the list. Every move then beats half of the others and loses to the rest, like
+/// rock, paper, scissors, or rock, paper, scissors, spock, lizard.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "RawRuleset")]
+pub struct Ruleset {
+    moves: Vec<Move>,
+    points: Points,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRuleset {
+    moves: Vec<Move>,
+    #[serde(default)]
+    points: Points,
+}
+
+impl TryFrom<RawRuleset> for Ruleset {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawRuleset) -> Result<Self> {
+        Self::new(raw.moves, raw.points)
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        let moves = [
+            ("rock", 'A', 'X'),
+            ("paper", 'B', 'Y'),
+            ("scissors", 'C', 'Z'),
+        ];
+
+        Self {
+            moves: moves
+                .into_iter()
+                .zip(1..)
+                .map(|((name, their, mine), score)| Move {
+                    name: name.to_string(),
+                    score,
+                    their,
+                    mine,
+                })
+                .collect(),
+            points: Points::default(),
+        }
+    }
+}
+
+impl Ruleset {
+    pub fn new(moves: Vec<Move>, points: Points) -> Result<Self> {
+        ensure!(
+            moves.len() >= 3 && moves.len() % 2 == 1,
+            "A game needs an odd number of moves, at least 3, got {}",
+            moves.len()
+        );
+
+        for (idx, mv) in moves.iter().enumerate() {
+            ensure!(
+                !["lose", "draw", "win"].contains(&mv.name.as_str()),
+                "A move cannot be called `{}`, which is an outcome",
+                mv.name
+            );
+
+            for letter in [mv.their, mv.mine] {
+                ensure!(
+                    letter.is_ascii_uppercase(),
+                    "Moves are written as uppercase letters, got `{letter}` for {}",
+                    mv.name
+                );
+            }
+
+            if let Some(other) = moves[..idx].iter().find(|other| {
+                other.name == mv.name || other.their == mv.their || other.mine == mv.mine
+            }) {
+                bail!(
+                    "The moves {} and {} have the same name or letter",
+                    other.name,
+                    mv.name
+                );
+            }
+        }
+
+        Ok(Self { moves, points })
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    pub fn get(&self, choice: Choice) -> &Move {
+        &self.moves[choice.0]
+    }
+
+    /// The move called `name`.
+    pub fn named(&self, name: &str) -> Result<Choice> {
+        self.find(|mv| mv.name == name)
+            .ok_or_else(|| anyhow!("Unknown move `{name}`, expected one of {}", self.list()))
+    }
+
+    /// The move written as `letter` in the first column of the guide.
+    pub fn theirs(&self, letter: char) -> Result<Choice> {
+        self.find(|mv| mv.their == letter)
+            .ok_or_else(|| self.unknown(letter, |mv| mv.their))
+    }
+
+    /// The move written as `letter` in the second column of the guide.
+    pub fn mine(&self, letter: char) -> Result<Choice> {
+        self.find(|mv| mv.mine == letter)
+            .ok_or_else(|| self.unknown(letter, |mv| mv.mine))
+    }
+
+    fn find(&self, pred: impl Fn(&Move) -> bool) -> Option<Choice> {
+        self.moves.iter().position(pred).map(Choice)
+    }
+
+    fn list(&self) -> String {
+        let names: Vec<&str> = self.moves.iter().map(|mv| mv.name.as_str()).collect();
+        names.join(", ")
+    }
+
+    fn unknown(&self, letter: char, column: fn(&Move) -> char) -> anyhow::Error {
+        let letters: Vec<String> = self.moves.iter().map(|mv| column(mv).to_string()).collect();
+        anyhow!("Expected one of {}, got `{letter}`", letters.join(", "))
+    }
+
+    /// How the round ends for me when playing `mine` against `their`.
+    pub fn outcome(&self, mine: Choice, their: Choice) -> Outcome {
+        match (mine.0 + self.moves.len() - their.0) % self.moves.len() {
+            0 => Outcome::Draw,
+            places if places % 2 == 1 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// A move that beats `their`: the one right after it.
+    pub fn choose_to_defeat(&self, their: Choice) -> Choice {
+        Choice((their.0 + 1) % self.moves.len())
+    }
+
+    /// A move that loses to `their`: the one right before it.
+    pub fn choose_to_lose(&self, their: Choice) -> Choice {
+        Choice((their.0 + self.moves.len() - 1) % self.moves.len())
+    }
+
+    /// The move to play against `their` for the round to end with `outcome`.
+    pub fn response_for(&self, their: Choice, outcome: Outcome) -> Choice {
+        match outcome {
+            Outcome::Lose => self.choose_to_lose(their),
+            Outcome::Draw => their,
+            Outcome::Win => self.choose_to_defeat(their),
+        }
+    }
+
+    pub fn calculate_score(&self, mine: Choice, their: Choice) -> usize {
+        let outcome = match self.outcome(mine, their) {
+            Outcome::Lose => self.points.loss,
+            Outcome::Draw => self.points.draw,
+            Outcome::Win => self.points.win,
+        };
+
+        outcome + self.get(mine).score
+    }
+}
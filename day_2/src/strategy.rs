@@ -0,0 +1,140 @@
+//! How the second column of the strategy guide is read. The puzzle reads it one way in each part,
+//! and other readings can be given as mappings in the parameters:
+//!
+//! ```toml
+//! [day_2]
+//! part1 = "mirror"
+//!
+//! [day_2.strategies.mirror]
+//! X = "scissors"
+//! Y = "paper"
+//! Z = "rock"
+//! ```
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+
+use crate::{
+    rules::{Choice, Ruleset},
+    Outcome,
+};
+
+/// Picks my move for a round from their move and the response the guide gives.
+pub trait Strategy {
+    fn choose(&self, rules: &Ruleset, their: Choice, response: char) -> Result<Choice>;
+}
+
+/// The responses are the moves to play, written like in the [`Ruleset`]: X, Y and Z for rock,
+/// paper and scissors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Moves;
+
+/// X, Y and Z are how the round needs to end: lose, draw and win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcomes;
+
+impl Strategy for Moves {
+    fn choose(&self, rules: &Ruleset, _: Choice, response: char) -> Result<Choice> {
+        rules.mine(response)
+    }
+}
+
+impl Strategy for Outcomes {
+    fn choose(&self, rules: &Ruleset, their: Choice, response: char) -> Result<Choice> {
+        let outcome = match response {
+            'X' => Outcome::Lose,
+            'Y' => Outcome::Draw,
+            'Z' => Outcome::Win,
+            _ => bail!("Expected one of X, Y or Z, got `{response}`"),
+        };
+
+        Ok(rules.response_for(their, outcome))
+    }
+}
+
+/// What a response of a [`Mapping`] stands for: one of the outcomes `lose`, `draw` and `win`, or
+/// else the name of a move.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum Response {
+    Play(String),
+    Aim(Outcome),
+}
+
+impl From<String> for Response {
+    fn from(response: String) -> Self {
+        match response.as_str() {
+            "lose" => Self::Aim(Outcome::Lose),
+            "draw" => Self::Aim(Outcome::Draw),
+            "win" => Self::Aim(Outcome::Win),
+            _ => Self::Play(response),
+        }
+    }
+}
+
+/// A strategy defined in the parameters, giving a move or an outcome for each response.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Mapping(pub BTreeMap<char, Response>);
+
+impl Mapping {
+    /// Fails if a response names a move that is not part of `rules`.
+    pub fn check(&self, rules: &Ruleset) -> Result<()> {
+        for (letter, response) in &self.0 {
+            if let Response::Play(name) = response {
+                rules
+                    .named(name)
+                    .with_context(|| format!("Invalid response for `{letter}`"))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Strategy for Mapping {
+    fn choose(&self, rules: &Ruleset, their: Choice, response: char) -> Result<Choice> {
+        match self.0.get(&response) {
+            Some(Response::Play(name)) => rules.named(name),
+            Some(Response::Aim(outcome)) => Ok(rules.response_for(their, *outcome)),
+            None => bail!("The strategy has no mapping for `{response}`"),
+        }
+    }
+}
+
+/// Names of the strategies that are always available.
+pub const BUILT_IN: [&str; 2] = ["moves", "outcomes"];
+
+/// The built-in strategy called `name`, or else the one defined in `strategies`. Every strategy
+/// defined is checked against `rules`, so a typo is caught even if the strategy is not used.
+pub fn named(
+    name: &str,
+    strategies: &BTreeMap<String, Mapping>,
+    rules: &Ruleset,
+) -> Result<Box<dyn Strategy>> {
+    if let Some(redefined) = BUILT_IN.iter().find(|&&name| strategies.contains_key(name)) {
+        bail!("The strategy `{redefined}` is built in and cannot be redefined");
+    }
+
+    for (defined, mapping) in strategies {
+        mapping
+            .check(rules)
+            .with_context(|| format!("Invalid strategy `{defined}`"))?;
+    }
+
+    match name {
+        "moves" => Ok(Box::new(Moves)),
+        "outcomes" => Ok(Box::new(Outcomes)),
+        _ => strategies
+            .get(name)
+            .map(|mapping| Box::new(mapping.clone()) as Box<dyn Strategy>)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Unknown strategy `{name}`, expected {} or one defined in `strategies`",
+                    BUILT_IN.join(", ")
+                )
+            }),
+    }
+}
@@ -0,0 +1,122 @@
+use day_2::{
+    rules::{Move, Points, Ruleset},
+    Outcome, Params, Round,
+};
+use utils::{Param, RawParams, Solver};
+
+const RPSLS: [(&str, char, char); 5] = [
+    ("rock", 'A', 'X'),
+    ("paper", 'B', 'Y'),
+    ("scissors", 'C', 'Z'),
+    ("spock", 'D', 'V'),
+    ("lizard", 'E', 'W'),
+];
+
+fn moves(names: &[(&str, char, char)]) -> Vec<Move> {
+    names
+        .iter()
+        .zip(1..)
+        .map(|(&(name, their, mine), score)| Move {
+            name: name.to_string(),
+            score,
+            their,
+            mine,
+        })
+        .collect()
+}
+
+fn rpsls() -> Ruleset {
+    Ruleset::new(moves(&RPSLS), Points::default()).unwrap()
+}
+
+#[test]
+fn every_move_beats_half_of_the_others() {
+    let rules = rpsls();
+    let choice = |name| rules.named(name).unwrap();
+
+    let wins = [
+        ("scissors", "paper"),
+        ("paper", "rock"),
+        ("rock", "lizard"),
+        ("lizard", "spock"),
+        ("spock", "scissors"),
+        ("scissors", "lizard"),
+        ("lizard", "paper"),
+        ("paper", "spock"),
+        ("spock", "rock"),
+        ("rock", "scissors"),
+    ];
+
+    for (winner, loser) in wins {
+        assert_eq!(rules.outcome(choice(winner), choice(loser)), Outcome::Win);
+        assert_eq!(rules.outcome(choice(loser), choice(winner)), Outcome::Lose);
+    }
+
+    for (name, _, _) in RPSLS {
+        let their = choice(name);
+        assert_eq!(rules.outcome(their, their), Outcome::Draw);
+
+        for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+            let mine = rules.response_for(their, outcome);
+            assert_eq!(rules.outcome(mine, their), outcome, "against {name}");
+        }
+    }
+
+    // spock vaporizes rock
+    assert_eq!(
+        rules.calculate_score(choice("spock"), choice("rock")),
+        4 + 6
+    );
+}
+
+#[test]
+fn games_need_an_odd_number_of_distinct_moves() {
+    let err = |moves, points| Ruleset::new(moves, points).unwrap_err().to_string();
+
+    assert_eq!(
+        err(moves(&RPSLS[..4]), Points::default()),
+        "A game needs an odd number of moves, at least 3, got 4"
+    );
+    assert!(err(moves(&RPSLS[..1]), Points::default()).contains("got 1"));
+
+    let mut duplicate = moves(&RPSLS[..3]);
+    duplicate[2].mine = 'X';
+    assert_eq!(
+        err(duplicate, Points::default()),
+        "The moves rock and scissors have the same name or letter"
+    );
+
+    let mut outcome = moves(&RPSLS[..3]);
+    outcome[1].name = "win".to_string();
+    assert!(err(outcome, Points::default()).contains("`win`"));
+}
+
+#[test]
+fn rulesets_are_read_from_the_params() {
+    let mut params = RawParams::default();
+    let rules: Param = "rules={ points = { win = 10 }, moves = [\
+        { name = \"rock\", score = 1, their = \"A\", mine = \"X\" }, \
+        { name = \"paper\", score = 2, their = \"B\", mine = \"Y\" }, \
+        { name = \"scissors\", score = 3, their = \"C\", mine = \"Z\" }, \
+        { name = \"spock\", score = 4, their = \"D\", mine = \"V\" }, \
+        { name = \"lizard\", score = 5, their = \"E\", mine = \"W\" }] }"
+        .parse()
+        .unwrap();
+    params.set(&rules.key, rules.value);
+
+    let params: Params = params.get().unwrap();
+    assert_eq!(params.rules.moves().len(), 5);
+
+    // spock loses to lizard, lizard draws with lizard, and scissors win against paper
+    let rounds = Round::parse("E V\nE W\nB Z\n").unwrap();
+    assert_eq!(Round::part1(&rounds, &params).unwrap(), 4 + 5 + 3 + 3 + 10);
+    // lizard loses to rock, draws with spock, and scissors win against paper
+    let rounds = Round::parse("A X\nD Y\nB Z\n").unwrap();
+    assert_eq!(Round::part2(&rounds, &params).unwrap(), 5 + 4 + 3 + 3 + 10);
+
+    let err = Round::part1(&Round::parse("F X\n").unwrap(), &params).unwrap_err();
+    assert_eq!(
+        format!("{err:#}"),
+        "Invalid round on line 1: Expected one of A, B, C, D, E, got `F`"
+    );
+}
@@ -0,0 +1,129 @@
+use std::{collections::BTreeMap, fs};
+
+use day_2::{
+    rules::{Move, Points, Ruleset},
+    strategy::{self, Mapping, Moves, Outcomes, Response, Strategy},
+    total_score, Outcome, Params, Round,
+};
+use utils::{Day, Overrides, Solver};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn built_in_strategies_read_the_guide_like_the_puzzle() {
+    let rounds = Round::parse(EXAMPLE).unwrap();
+    let rules = Ruleset::default();
+    let [rock, paper] = ["rock", "paper"].map(|name| rules.named(name).unwrap());
+
+    assert_eq!(Moves.choose(&rules, rock, 'Y').unwrap(), paper);
+    assert_eq!(Outcomes.choose(&rules, rock, 'Y').unwrap(), rock);
+    assert_eq!(total_score(&rounds, &rules, &Moves).unwrap(), 15);
+    assert_eq!(total_score(&rounds, &rules, &Outcomes).unwrap(), 12);
+
+    // both parts are solved with their own strategy in a single run
+    let params = Params {
+        part1: "outcomes".to_string(),
+        part2: "moves".to_string(),
+        ..Params::default()
+    };
+    assert_eq!(Round::part1(&rounds, &params).unwrap(), 12);
+    assert_eq!(Round::part2(&rounds, &params).unwrap(), 15);
+}
+
+#[test]
+fn mappings_give_moves_or_outcomes() {
+    let rules = Ruleset::default();
+    let [paper, scissors] = ["paper", "scissors"].map(|name| rules.named(name).unwrap());
+    let mapping = Mapping(BTreeMap::from([
+        ('X', Response::Play("scissors".to_string())),
+        ('Y', Response::Aim(Outcome::Win)),
+        ('Z', Response::Play("lizard".to_string())),
+    ]));
+
+    assert_eq!(mapping.choose(&rules, paper, 'X').unwrap(), scissors);
+    assert_eq!(mapping.choose(&rules, paper, 'Y').unwrap(), scissors);
+    assert_eq!(
+        mapping.choose(&rules, paper, 'W').unwrap_err().to_string(),
+        "The strategy has no mapping for `W`"
+    );
+    assert_eq!(
+        mapping.choose(&rules, paper, 'Z').unwrap_err().to_string(),
+        "Unknown move `lizard`, expected one of rock, paper, scissors"
+    );
+}
+
+#[test]
+fn strategies_are_defined_in_the_config_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("params.toml");
+    fs::write(
+        &path,
+        "[day_2]\npart2 = \"cheat\"\n\n\
+         [day_2.strategies.cheat]\nX = \"win\"\nY = \"win\"\nZ = \"win\"\n",
+    )
+    .unwrap();
+
+    let params = Overrides::load(&path).unwrap().apply(2, Default::default());
+    let answers = Day::new::<Round>().solve(EXAMPLE, &params).unwrap();
+
+    assert_eq!(answers.part1.unwrap(), "15");
+    // paper, rock and scissors, each winning
+    assert_eq!(answers.part2.unwrap(), "24");
+}
+
+#[test]
+fn unknown_or_redefined_strategies_are_errors() {
+    let rules = Ruleset::default();
+    let strategies = BTreeMap::new();
+    let err = strategy::named("mirror", &strategies, &rules)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("Unknown strategy `mirror`"));
+
+    let strategies = BTreeMap::from([("moves".to_string(), Mapping(BTreeMap::new()))]);
+    assert!(strategy::named("outcomes", &strategies, &rules).is_err());
+
+    assert_eq!(
+        Response::from("win".to_string()),
+        Response::Aim(Outcome::Win)
+    );
+}
+
+#[test]
+fn mappings_are_checked_against_the_rules_even_if_unused() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("params.toml");
+    fs::write(
+        &path,
+        "[day_2.strategies.typo]\nX = \"rokc\"\nY = \"paper\"\nZ = \"win\"\n",
+    )
+    .unwrap();
+
+    let params = Overrides::load(&path).unwrap().apply(2, Default::default());
+    let answers = Day::new::<Round>().solve(EXAMPLE, &params).unwrap();
+
+    let err = answers.part1.unwrap_err();
+    assert_eq!(
+        format!("{err:#}"),
+        "Invalid strategy `typo`: Invalid response for `X`: \
+         Unknown move `rokc`, expected one of rock, paper, scissors"
+    );
+
+    // only moves of the rules in use can be played
+    let mut moves = Ruleset::default().moves().to_vec();
+    for (name, score, their, mine) in [("spock", 4, 'D', 'V'), ("lizard", 5, 'E', 'W')] {
+        moves.push(Move {
+            name: name.to_string(),
+            score,
+            their,
+            mine,
+        });
+    }
+    let rules = Ruleset::new(moves, Points::default()).unwrap();
+    let lizard = Mapping(BTreeMap::from([(
+        'X',
+        Response::from("lizard".to_string()),
+    )]));
+    assert!(lizard.check(&Ruleset::default()).is_err());
+    assert!(lizard.check(&rules).is_ok());
+}
@@ -0,0 +1,105 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use utils::{Cursor, FromCursor, ParseError, Solver};
+
+fn find_score(input: &str) -> usize {
+    let first_half = input.chars().take(input.len() / 2);
+    let mut second_half = input.chars().skip(input.len() / 2);
+
+    let mut found: HashSet<char> = HashSet::with_capacity(input.len());
+
+    for c in first_half {
+        found.insert(c);
+    }
+
+    second_half
+        .find(|c| found.contains(c))
+        .map(|c| c.score())
+        .unwrap_or(0)
+}
+
+fn find_common(input: &[&str]) -> usize {
+    let mut common: HashMap<char, usize> =
+        HashMap::with_capacity(input.iter().map(|line| line.len()).max().unwrap_or(0));
+
+    for (line_num, line) in input.iter().enumerate() {
+        let line_num = line_num + 1;
+        for c in line.chars() {
+            let entry = common.entry(c).or_insert(0);
+
+            if *entry == line_num - 1 {
+                *entry = line_num;
+            }
+        }
+    }
+
+    let common = common.iter().find(|(_, val)| **val == 3);
+    let common = common.iter().find(|(_, val)| **val == 3);
+
+    common.map(|(c, _)| c.score()).unwrap_or(0)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    items: String,
+}
+
+impl FromCursor for Rucksack {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        match cursor.take_while(|ch| ch.is_ascii_alphabetic()) {
+            "" => Err(cursor.error("items `a`-`z` or `A`-`Z`")),
+            items => Ok(Self {
+                items: items.into(),
+            }),
+        }
+    }
+}
+
+impl Solver for Rucksack {
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<Rucksack>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(utils::parse_lines(input)?)
+    }
+
+    fn part1(rucksacks: &Self::Parsed, _: &()) -> Result<usize> {
+        let result = rucksacks
+            .iter()
+            .map(|rucksack| find_score(&rucksack.items))
+            .sum();
+
+        Ok(result)
+    }
+
+    fn part2(rucksacks: &Self::Parsed, _: &()) -> Result<usize> {
+        let result = rucksacks
+            .iter()
+            .map(|rucksack| rucksack.items.as_str())
+            .collect::<Vec<_>>()
+            .chunks(3)
+            .map(find_common)
+            .sum();
+
+        Ok(result)
+    }
+}
+
+trait CharScore {
+    fn score(&self) -> usize;
+}
+
+impl CharScore for char {
+    fn score(&self) -> usize {
+        if self.is_uppercase() {
+            (*self as usize) - 64 + 26
+        } else {
+            (*self as usize) - 96
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use utils::{Cursor, FromCursor, IntervalSet, ParseError, Solver};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Task {
+    sections: IntervalSet<usize>,
+}
+
+impl FromStr for Task {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Task {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let start = cursor.unsigned()?;
+        cursor.literal("-")?;
+        let end = cursor.unsigned()?;
+
+        Ok(Self {
+            sections: (start..=end).into(),
+        })
+    }
+}
+
+impl Task {
+    fn full_overlap_with(&self, other: &Task) -> bool {
+        self.sections.is_superset(&other.sections) || other.sections.is_superset(&self.sections)
+    }
+
+    fn partial_overlap_with(&self, other: &Task) -> bool {
+        self.sections.overlaps(&other.sections)
+    }
+}
+
+impl Solver for Task {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Task>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let pairs = Cursor::new(input).lines(|cursor| {
+            let first = cursor.parse()?;
+            cursor.literal(",")?;
+            let second = cursor.parse()?;
+
+            Ok([first, second])
+        })?;
+
+        Ok(pairs.into_iter().flatten().collect())
+    }
+
+    fn part1(tasks: &Self::Parsed, _: &()) -> Result<usize> {
+        let result = tasks
+            .chunks(2)
+            .filter(|&tasks| tasks[0].full_overlap_with(&tasks[1]))
+            .count();
+
+        Ok(result)
+    }
+
+    fn part2(tasks: &Self::Parsed, _: &()) -> Result<usize> {
+        let result = tasks
+            .chunks(2)
+            .filter(|tasks| tasks[0].partial_overlap_with(&tasks[1]))
+            .count();
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context, Result};
+use tracing::trace;
+use utils::{Cursor, FromCursor, ParseError, Solver};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Stack {
+    letters: VecDeque<char>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Stacks {
+    stacks: BTreeMap<usize, Stack>,
+}
+
+impl Stacks {
+    fn apply_movement(&mut self, Movement { from, to, how_many }: Movement) -> Result<()> {
+        trace!(from, to, how_many, "move one by one");
+
+        self.take(from, how_many)?;
+        self.stack(to)?;
+
+        for _ in 0..how_many {
+            let package = self
+                .stack(from)?
+                .letters
+                .pop_back()
+                .expect("crates counted");
+            self.stack(to)?.letters.push_back(package);
+        }
+
+        Ok(())
+    }
+
+    fn apply_movement_9001(&mut self, Movement { from, to, how_many }: Movement) -> Result<()> {
+        trace!(from, to, how_many, "move at once");
+
+        let start_index = self.take(from, how_many)?;
+        self.stack(to)?;
+
+        let packages: Vec<char> = self.stack(from)?.letters.drain(start_index..).collect();
+        self.stack(to)?.letters.extend(packages);
+
+        Ok(())
+    }
+
+    fn stack(&mut self, id: usize) -> Result<&mut Stack> {
+        self.stacks
+            .get_mut(&id)
+            .ok_or_else(|| anyhow!("There is no stack {id}"))
+    }
+
+    /// Index of the lowest of the top `how_many` crates of stack `id`, failing if it has fewer.
+    fn take(&mut self, id: usize, how_many: usize) -> Result<usize> {
+        let len = self.stack(id)?.letters.len();
+
+        len.checked_sub(how_many).ok_or_else(|| {
+            anyhow!("Cannot move {how_many} crates from stack {id}, which has {len}")
+        })
+    }
+
+    fn top_crates(&self) -> String {
+        self.stacks
+            .values()
+            .filter_map(|stack| stack.letters.back())
+            .collect()
+    }
+}
+
+/// Reads one row of the drawing, e.g. `[Z] [M] [P]`, where each position is either a crate or
+/// three spaces.
+fn crate_row(cursor: &mut Cursor<'_>) -> Result<Vec<Option<char>>, ParseError> {
+    let mut row = Vec::new();
+
+    loop {
+        if cursor.eat("[") {
+            row.push(Some(cursor.satisfy("crate letter", char::is_alphabetic)?));
+            cursor.literal("]")?;
+        } else {
+            cursor.literal("   ")?;
+            row.push(None);
+        }
+
+        if !cursor.eat(" ") || cursor.is_at_line_end() {
+            break;
+        }
+    }
+
+    cursor.line_end()?;
+
+    Ok(row)
+}
+
+impl FromCursor for Stacks {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let mut rows = Vec::new();
+
+        loop {
+            let mut peek = *cursor;
+            peek.spaces();
+
+            if peek.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+                break;
+            }
+
+            rows.push(crate_row(cursor)?);
+        }
+
+        // stack numbers below the drawing
+        let numbers = cursor.lines(|cursor| {
+            let mut numbers: Vec<usize> = Vec::new();
+
+            while !cursor.is_at_line_end() {
+                cursor.spaces();
+                numbers.push(cursor.unsigned()?);
+                cursor.spaces();
+            }
+
+            Ok(numbers)
+        })?;
+
+        // stacks that start out empty still take crates
+        let mut stacks: BTreeMap<usize, Stack> = numbers
+            .into_iter()
+            .flatten()
+            .map(|number| (number, Stack::default()))
+            .collect();
+
+        for row in rows.iter().rev() {
+            for (n, symbol) in row.iter().enumerate() {
+                if let Some(symbol) = symbol {
+                    stacks.entry(n + 1).or_default().letters.push_back(*symbol);
+                }
+            }
+        }
+
+        Ok(Stacks { stacks })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Movement {
+    from: usize,
+    to: usize,
+    how_many: usize,
+}
+
+impl FromStr for Movement {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(input)
+    }
+}
+
+impl FromCursor for Movement {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        // input looks like:
+        // move 1 from 2 to 1
+        cursor.literal("move ")?;
+        let how_many = cursor.unsigned()?;
+        cursor.literal(" from ")?;
+        let from = cursor.unsigned()?;
+        cursor.literal(" to ")?;
+        let to = cursor.unsigned()?;
+
+        Ok(Movement { from, to, how_many })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Movements(Vec<Movement>);
+
+impl Deref for Movements {
+    type Target = Vec<Movement>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Movements {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<Movement>> for Movements {
+    fn from(input: Vec<Movement>) -> Self {
+        Self(input)
+    }
+}
+
+impl FromCursor for Movements {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let movements = cursor.lines(Movement::from_cursor)?;
+
+        Ok(movements.into())
+    }
+}
+
+impl Solver for Stacks {
+    const DAY: u8 = 5;
+
+    type Parsed = (Stacks, Movements);
+    type Answer1 = String;
+    type Answer2 = String;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut cursor = Cursor::new(input);
+
+        let stacks: Stacks = cursor.parse()?;
+        cursor.line_end()?;
+        let movements: Movements = cursor.parse()?;
+        cursor.finish()?;
+
+        Ok((stacks, movements))
+    }
+
+    fn part1((stacks, movements): &Self::Parsed, _: &()) -> Result<String> {
+        let mut stacks = stacks.clone();
+        for (idx, movement) in movements.iter().enumerate() {
+            stacks
+                .apply_movement(*movement)
+                .with_context(|| format!("Invalid move {}", idx + 1))?;
+        }
+
+        Ok(stacks.top_crates())
+    }
+
+    fn part2((stacks, movements): &Self::Parsed, _: &()) -> Result<String> {
+        let mut stacks = stacks.clone();
+        for (idx, movement) in movements.iter().enumerate() {
+            stacks
+                .apply_movement_9001(*movement)
+                .with_context(|| format!("Invalid move {}", idx + 1))?;
+        }
+
+        Ok(stacks.top_crates())
+    }
+}
@@ -0,0 +1,41 @@
+use day_5::Stacks;
+use utils::Solver;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+fn solve(input: &str) -> (String, String) {
+    let parsed = Stacks::parse(input).unwrap();
+    let answer = |res: anyhow::Result<String>| match res {
+        Ok(answer) => answer,
+        Err(err) => format!("error: {err:#}"),
+    };
+
+    (
+        answer(Stacks::part1(&parsed, &())),
+        answer(Stacks::part2(&parsed, &())),
+    )
+}
+
+#[test]
+fn crates_are_moved_like_the_puzzle() {
+    assert_eq!(solve(EXAMPLE), ("CMZ".to_string(), "MCD".to_string()));
+
+    // a stack that starts out empty
+    let input = "[A]    \n 1   2 \n\nmove 1 from 1 to 2\n";
+    assert_eq!(solve(input), ("A".to_string(), "A".to_string()));
+}
+
+#[test]
+fn moves_that_cannot_be_made_are_errors() {
+    let unknown = EXAMPLE.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+    let err = "error: Invalid move 4: There is no stack 4";
+    assert_eq!(solve(&unknown), (err.to_string(), err.to_string()));
+
+    let unknown = EXAMPLE.replace("move 1 from 2 to 1", "move 1 from 0 to 1");
+    let err = "error: Invalid move 1: There is no stack 0";
+    assert_eq!(solve(&unknown), (err.to_string(), err.to_string()));
+
+    let too_many = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+    let err = "error: Invalid move 2: Cannot move 4 crates from stack 1, which has 3";
+    assert_eq!(solve(&too_many), (err.to_string(), err.to_string()));
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, ensure, Result};
+use serde::Deserialize;
+use utils::{Cursor, FromCursor, ParseError, Solver};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signal {
+    data: String,
+}
+
+impl FromCursor for Signal {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let data = cursor.take_while(|ch| ch.is_ascii_lowercase());
+
+        if data.is_empty() {
+            return Err(cursor.error("signal of lowercase letters"));
+        }
+
+        cursor.line_end()?;
+
+        Ok(Signal { data: data.into() })
+    }
+}
+
+impl Signal {
+    fn marker(&self, size: usize) -> Result<usize> {
+        ensure!(size > 0, "A marker needs at least one character");
+
+        find_message_with_len(&self.data, size)
+            .ok_or_else(|| anyhow!("No marker of length {size} found"))
+    }
+}
+
+/// Number of different characters in a row that make up each kind of marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub packet_marker: usize,
+    pub message_marker: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            packet_marker: 4,
+            message_marker: 14,
+        }
+    }
+}
+
+impl Solver for Signal {
+    const DAY: u8 = 6;
+
+    type Parsed = Signal;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(utils::parse_str(input)?)
+    }
+
+    fn part1(signal: &Self::Parsed, params: &Params) -> Result<usize> {
+        signal.marker(params.packet_marker)
+    }
+
+    fn part2(signal: &Self::Parsed, params: &Params) -> Result<usize> {
+        signal.marker(params.message_marker)
+    }
+}
+
+fn find_message_with_len(input: &str, size: usize) -> Option<usize> {
+    let input: Vec<char> = input.chars().collect();
+
+    let mut map: BTreeMap<char, usize> = BTreeMap::new();
+
+    for (index, chars) in input.windows(size).enumerate() {
+        for letter in chars {
+            *map.entry(*letter).or_default() += 1;
+        }
+
+        if map.values().all(|count| *count == 1) {
+            return Some(index + size);
+        } else {
+            map.clear();
+        }
+    }
+
+    None
+}
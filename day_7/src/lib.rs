@@ -0,0 +1,432 @@
+// this can be solved as a depth first search, see ThePrimeagen's solution for that
+//
+// However, as an excersize, it would be interesting to try and solve this by first parsing the
+// filesystem and generating a tree
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use petgraph::{
+    stable_graph::{NodeIndex, StableGraph},
+    Direction,
+};
+use serde::Deserialize;
+use utils::{Cursor, FromCursor, ParseError, Solver};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct File {
+    name: String,
+    size: Size,
+}
+
+impl FromStr for File {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for File {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let size = cursor.parse()?;
+        cursor.literal(" ")?;
+        let name = name(cursor, "file name")?;
+
+        Ok(Self { name, size })
+    }
+}
+
+fn name(cursor: &mut Cursor<'_>, expected: &str) -> Result<String, ParseError> {
+    match cursor.rest_of_line() {
+        "" => Err(cursor.error(expected)),
+        name => Ok(name.into()),
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Size {
+    #[default]
+    Unknown,
+    Known(usize),
+}
+impl Size {
+    fn into_inner(self) -> usize {
+        match self {
+            Size::Unknown => 0,
+            Size::Known(size) => size,
+        }
+    }
+}
+
+impl FromStr for Size {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Size {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        Ok(Self::Known(cursor.unsigned()?))
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Dir {
+    name: String,
+    size: Size,
+    nodes: BTreeMap<String, Node>,
+}
+
+impl FromStr for Dir {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Dir {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        cursor.literal("dir ")?;
+
+        Ok(Self {
+            name: name(cursor, "directory name")?,
+            ..Default::default()
+        })
+    }
+}
+
+impl Dir {
+    fn calculate_size(&mut self) {
+        let mut dir_size = 0;
+
+        for node in self.nodes.values_mut() {
+            let size = match node {
+                Node::Dir(dir) => {
+                    if let Size::Unknown = dir.size {
+                        dir.calculate_size();
+                    }
+
+                    dir.size
+                }
+                Node::File(file) => file.size,
+            };
+
+            if let Size::Known(size) = size {
+                dir_size += size;
+            }
+        }
+
+        self.size = Size::Known(dir_size);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Node {
+    Dir(Dir),
+    File(File),
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Node {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        if cursor.rest().starts_with("dir") {
+            Dir::from_cursor(cursor).map(|dir| dir.into())
+        } else {
+            File::from_cursor(cursor).map(|file| file.into())
+        }
+    }
+}
+
+/// A line of the terminal output: either a command or an entry listed by `ls`.
+enum Line {
+    Cd(String),
+    Ls,
+    Node(Node),
+}
+
+impl FromCursor for Line {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        if cursor.eat("$ cd ") {
+            Ok(Line::Cd(name(cursor, "directory name")?))
+        } else if cursor.eat("$ ls") {
+            Ok(Line::Ls)
+        } else if cursor.rest().starts_with('$') {
+            Err(cursor.error("command `cd` or `ls`"))
+        } else {
+            cursor.parse().map(Line::Node)
+        }
+    }
+}
+
+impl Node {
+    fn name(&self) -> &str {
+        match self {
+            Node::Dir(dir) => &dir.name,
+            Node::File(file) => &file.name,
+        }
+    }
+
+    fn as_dir_mut(&mut self) -> &mut Dir {
+        match self {
+            Node::Dir(dir) => dir,
+            _ => panic!("Can't return File as Dir!"),
+        }
+    }
+
+    fn calculate_size(&mut self) {
+        if let Self::Dir(dir) = self {
+            // size for files is always known
+            dir.calculate_size();
+        }
+    }
+
+    fn dir(&self) -> Option<&Dir> {
+        match self {
+            Node::Dir(dir) => Some(dir),
+            Node::File(_) => None,
+        }
+    }
+
+    fn size(&self) -> usize {
+        let size = match self {
+            Node::Dir(dir) => dir.size,
+            Node::File(file) => file.size,
+        };
+
+        match size {
+            Size::Unknown => 0,
+            Size::Known(size) => size,
+        }
+    }
+}
+
+impl From<Dir> for Node {
+    fn from(dir: Dir) -> Self {
+        Self::Dir(dir)
+    }
+}
+
+impl From<File> for Node {
+    fn from(file: File) -> Self {
+        Self::File(file)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Filesystem {
+    dirs: StableGraph<Node, ()>,
+}
+
+impl Filesystem {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let mut tree: StableGraph<Node, ()> = StableGraph::new();
+        let mut curr = None;
+
+        for line in Cursor::new(input).lines(Line::from_cursor)? {
+            if let Line::Cd(name) = line {
+                if name == ".." {
+                    let curr_node = tree.node_weight(curr.unwrap()).cloned();
+
+                    curr = tree
+                        .neighbors_directed(curr.unwrap(), Direction::Incoming)
+                        .next();
+
+                    if let Some(curr) = curr {
+                        tree.node_weight_mut(curr)
+                            .unwrap()
+                            .as_dir_mut()
+                            .nodes
+                            .insert(
+                                curr_node.as_ref().unwrap().name().into(),
+                                curr_node.unwrap(),
+                            );
+                    }
+                    continue;
+                }
+
+                // if we change to dir, add that dir as node to the graph
+                let dir = Dir {
+                    name,
+                    size: Size::Unknown,
+                    nodes: Default::default(),
+                };
+
+                let new = tree.add_node(dir.clone().into());
+
+                if let Some(curr) = curr {
+                    tree.add_edge(curr, new, ());
+                }
+
+                curr = Some(new);
+            } else if let Line::Node(new_node) = line {
+                if let Node::Dir(_) = new_node {
+                    // we only care about directories we visit -> cd dir_name
+                    continue;
+                }
+
+                let new = if let Some(node_idx) =
+                    tree.node_indices().find(|idx| tree[*idx] == new_node)
+                {
+                    node_idx
+                } else {
+                    tree.add_node(new_node.clone())
+                };
+
+                if let Some(node) = curr {
+                    let parent = tree.node_weight_mut(node).unwrap();
+
+                    if let Node::Dir(ref mut parent) = parent {
+                        parent.nodes.insert(new_node.name().into(), new_node);
+                        tree.add_edge(node, new, ());
+                    }
+                }
+            }
+        }
+
+        // might be that we never got back from the last directory...
+        while let Some(idx) = curr {
+            match tree.node_weight(idx) {
+                Some(node) if node.name() == "/" => break,
+                _ => {
+                    let curr_node = tree.node_weight(curr.unwrap()).cloned();
+
+                    curr = tree
+                        .neighbors_directed(curr.unwrap(), Direction::Incoming)
+                        .next();
+
+                    if let Some(curr) = curr {
+                        tree.node_weight_mut(curr)
+                            .unwrap()
+                            .as_dir_mut()
+                            .nodes
+                            .insert(
+                                curr_node.as_ref().unwrap().name().into(),
+                                curr_node.unwrap(),
+                            );
+                    }
+                }
+            }
+        }
+
+        Ok(Self { dirs: tree })
+    }
+
+    fn calculate_sizes(&mut self) {
+        for node in self.dirs.node_weights_mut() {
+            node.calculate_size();
+        }
+    }
+
+    fn iter(&self) -> Iter<'_> {
+        Iter {
+            filesystem: self,
+            index: 0,
+            indices: Vec::default(),
+        }
+    }
+}
+
+pub struct Iter<'a> {
+    filesystem: &'a Filesystem,
+    index: usize,
+    indices: Vec<NodeIndex>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.indices.is_empty() {
+            self.indices = self.filesystem.dirs.node_indices().collect();
+        }
+
+        let res = self
+            .filesystem
+            .dirs
+            .node_weight(*self.indices.get(self.index)?);
+
+        self.index += 1;
+
+        res
+    }
+}
+
+// invariants:
+// 1. Directed graph
+// 2. Each node has exactly one parent, or none for the root
+// 3. Each dir node can have 0 or many children
+// 4. File nodes don't have any children
+
+impl Filesystem {
+    fn dir_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter()
+            .filter_map(|node| node.dir())
+            .map(|dir| dir.size.into_inner())
+    }
+}
+
+/// Size of the disk and the free space the update needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub disk_size: usize,
+    pub needed_space: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            disk_size: 70_000_000,
+            needed_space: 30_000_000,
+        }
+    }
+}
+
+impl Solver for Filesystem {
+    const DAY: u8 = 7;
+
+    type Parsed = Filesystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut fs = Filesystem::from_input(input)?;
+        fs.calculate_sizes();
+
+        Ok(fs)
+    }
+
+    fn part1(fs: &Self::Parsed, _: &Params) -> Result<usize> {
+        Ok(fs.dir_sizes().filter(|size| *size <= 100_000).sum())
+    }
+
+    fn part2(fs: &Self::Parsed, params: &Params) -> Result<usize> {
+        let used_space = fs
+            .iter()
+            .map(|node| node.size())
+            .max()
+            .ok_or_else(|| anyhow!("Filesystem is empty"))?;
+        let free_space = params
+            .disk_size
+            .checked_sub(used_space)
+            .ok_or_else(|| anyhow!("The files take up more than the disk size"))?;
+        let needed_space = params.needed_space.saturating_sub(free_space);
+
+        fs.dir_sizes()
+            .filter(|size| *size >= needed_space)
+            .min()
+            .ok_or_else(|| anyhow!("No directory is large enough to delete"))
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use utils::{
+    Cells, Color, Cursor, Direction, Frame, Grid, ParseError, Pixel, Point, Recorder, Solver,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Tree {
+    height: usize,
+    visible: bool,
+    score: usize,
+}
+
+impl Display for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("[{};", self.height))?;
+        if self.visible {
+            f.write_str("t]")?;
+        } else {
+            f.write_str("f]")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<Tree>, ParseError> {
+    let mut cursor = Cursor::new(input);
+
+    let trees = Grid::parse_with(&mut cursor, |cursor| {
+        Ok(Tree {
+            height: cursor.digit()? as usize,
+            visible: false,
+            score: 0,
+        })
+    })?;
+    cursor.finish()?;
+
+    Ok(trees)
+}
+
+/// A tree is visible if all trees between it and the edge are lower in at least one direction.
+/// Trees on the edge are always visible.
+fn is_visible(trees: &Grid<Tree>, point: Point) -> bool {
+    let height = trees[point].height;
+
+    Direction::ALL.into_iter().any(|direction| {
+        trees
+            .ray(point, direction)
+            .all(|(_, tree)| tree.height < height)
+    })
+}
+
+/// Product of the viewing distances in all directions, each counting the trees up to and
+/// including the first one that is at least as high.
+fn tree_score(trees: &Grid<Tree>, point: Point) -> usize {
+    let height = trees[point].height;
+
+    Direction::ALL
+        .into_iter()
+        .map(|direction| {
+            let mut saw = 0;
+
+            for (_, tree) in trees.ray(point, direction) {
+                saw += 1;
+
+                if tree.height >= height {
+                    break;
+                }
+            }
+
+            saw
+        })
+        .product()
+}
+
+/// Draws the forest shaded by height, with the visibility of the trees in the first `revealed`
+/// rows shown and the tree with the best score highlighted once it is known.
+fn frame(trees: &Grid<Tree>, revealed: usize, best: Option<Point>) -> Frame {
+    Frame::of(trees, |point, tree| {
+        let Some(tree) = tree else {
+            return Pixel::BLANK;
+        };
+
+        let ch = char::from_digit(tree.height as u32 % 10, 10).unwrap_or('*');
+        let color = if Some(point) == best {
+            Color::RED
+        } else if point.y >= revealed {
+            Color::DARK_GREEN.blend(Color::GREEN, tree.height as f64 / 9.0)
+        } else if tree.visible {
+            Color::YELLOW
+        } else {
+            Color::DARK_GREY
+        };
+
+        Pixel::new(ch, color)
+    })
+}
+
+fn mark_trees(trees: &mut Grid<Tree>) {
+    let marks: Vec<_> = trees
+        .iter()
+        .map(|(point, _)| (point, is_visible(trees, point), tree_score(trees, point)))
+        .collect();
+
+    for (point, visible, score) in marks {
+        let tree = &mut trees[point];
+        tree.visible = visible;
+        tree.score = score;
+    }
+}
+
+impl Solver for Tree {
+    const DAY: u8 = 8;
+
+    type Parsed = Grid<Tree>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut trees = parse_input(input)?;
+        mark_trees(&mut trees);
+
+        Ok(trees)
+    }
+
+    fn part1(trees: &Self::Parsed, _: &()) -> Result<usize> {
+        Ok(trees.values().filter(|tree| tree.visible).count())
+    }
+
+    fn part2(trees: &Self::Parsed, _: &()) -> Result<usize> {
+        trees
+            .values()
+            .map(|tree| tree.score)
+            .max()
+            .ok_or_else(|| anyhow!("Forest is empty"))
+    }
+
+    // the visible trees of part 1 row by row, then the tree with the best score of part 2
+    fn animate(trees: &Self::Parsed, _: &(), recorder: &mut Recorder) -> Result<()> {
+        let best = trees
+            .iter()
+            .max_by_key(|(_, tree)| tree.score)
+            .map(|(point, _)| point)
+            .ok_or_else(|| anyhow!("Forest is empty"))?;
+
+        let rows = trees.bounds().map_or(0, |bounds| bounds.height());
+        for revealed in 0..=rows {
+            recorder.record_with(|| frame(trees, revealed, None));
+        }
+
+        recorder.record_with(|| frame(trees, rows, Some(best)));
+        recorder.finish_with(|| frame(trees, rows, Some(best)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use anyhow::{ensure, Result};
+use serde::Deserialize;
+use tracing::{debug, trace};
+use utils::{
+    Bounds, Color, Cursor, Direction, Frame, FromCursor, ParseError, Pixel, Point, Recorder, Solver,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Command {
+    direction: Direction,
+    count: usize,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        utils::parse_str(s)
+    }
+}
+
+impl FromCursor for Command {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let direction = cursor.parse()?;
+        cursor.literal(" ")?;
+        let count = cursor.unsigned()?;
+
+        Ok(Self { direction, count })
+    }
+}
+
+type Position = Point<isize>;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Rope {
+    segments: Vec<Position>,
+    tail_visits: BTreeSet<Position>,
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        let head = Default::default();
+        let tail = Default::default();
+        let tail_visits = [tail].into();
+
+        Self {
+            segments: vec![head, tail],
+            tail_visits,
+        }
+    }
+}
+
+impl Rope {
+    fn new(segments_count: usize) -> Self {
+        let segments = vec![Position::default(); segments_count];
+        let tail_visits = [segments[segments_count - 1]].into();
+
+        Self {
+            segments,
+            tail_visits,
+        }
+    }
+
+    fn apply(&mut self, command: Command) {
+        for _ in 0..command.count {
+            self.step(command.direction);
+        }
+    }
+
+    /// Moves the head by one and the knots following it.
+    fn step(&mut self, direction: Direction) {
+        let head = &mut self.segments[0];
+        *head = head.step(direction).expect("rope moved too far");
+
+        let num_of_segments = self.segments.windows(2).count();
+
+        for index in 0..num_of_segments {
+            if self.segment_len(index) > 1 {
+                self.move_segment(index);
+            }
+        }
+
+        // with a single knot, the head is the tail
+        let tail = self.segments[num_of_segments];
+        self.tail_visits.insert(tail);
+
+        trace!(head = ?self.segments[0], ?tail, "step");
+    }
+
+    fn move_segment(&mut self, index: usize) {
+        let head = self.segments[index];
+        let tail = &mut self.segments[index + 1];
+
+        *tail += (head - *tail).signum();
+    }
+
+    fn segment_len(&self, index: usize) -> usize {
+        self.segments[index].chebyshev(self.segments[index + 1])
+    }
+
+    /// Draws the part of the plane in `view`: the head, the knots following it by their number
+    /// and the positions the tail visited.
+    fn frame(&self, view: Bounds<isize>) -> Frame {
+        let mut frame = Frame::from_fn(view, |_| Pixel::new('.', Color::DARK_GREY));
+        let at = |point: Position| {
+            Point::new(
+                (point.x - view.min.x) as usize,
+                (point.y - view.min.y) as usize,
+            )
+        };
+
+        for &visit in &self.tail_visits {
+            frame.set(at(visit), Pixel::new('#', Color::GREY));
+        }
+        frame.set(at(Position::default()), Pixel::new('s', Color::BLUE));
+
+        // from the tail, so knots closer to the head are drawn over the ones behind them
+        let last = self.segments.len() - 1;
+        for (knot, &point) in self.segments.iter().enumerate().rev() {
+            let pixel = match knot {
+                0 => Pixel::new('H', Color::RED),
+                _ if knot == last => Pixel::new('T', Color::GREEN),
+                _ => {
+                    let ch = char::from_digit(knot as u32 % 10, 10).unwrap_or('*');
+                    Pixel::new(ch, Color::YELLOW)
+                }
+            };
+
+            frame.set(at(point), pixel);
+        }
+
+        frame
+    }
+}
+
+/// Bounds of every position of the head. Knots only ever move towards the one before them, so
+/// the whole rope stays within them.
+fn head_bounds(commands: &[Command]) -> Bounds<isize> {
+    let mut head = Position::default();
+    let mut bounds = Bounds::of_point(head);
+
+    for command in commands {
+        for _ in 0..command.count {
+            head = head.step(command.direction).expect("rope moved too far");
+            bounds.extend(head);
+        }
+    }
+
+    bounds
+}
+
+impl Rope {
+    fn tail_visits(segments_count: usize, commands: &[Command]) -> Result<usize> {
+        ensure!(segments_count > 0, "A rope needs at least one knot");
+
+        let mut rope = Rope::new(segments_count);
+
+        for command in commands.iter() {
+            rope.apply(*command);
+        }
+
+        debug!(
+            knots = segments_count,
+            visits = rope.tail_visits.len(),
+            "tail visits"
+        );
+
+        Ok(rope.tail_visits.len())
+    }
+}
+
+/// Number of knots of the rope in each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1_knots: usize,
+    pub part2_knots: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part1_knots: 2,
+            part2_knots: 10,
+        }
+    }
+}
+
+impl Solver for Rope {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Command>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(utils::parse_lines(input)?)
+    }
+
+    fn part1(commands: &Self::Parsed, params: &Params) -> Result<usize> {
+        Rope::tail_visits(params.part1_knots, commands)
+    }
+
+    fn part2(commands: &Self::Parsed, params: &Params) -> Result<usize> {
+        Rope::tail_visits(params.part2_knots, commands)
+    }
+
+    // the rope of part 2 moving step by step
+    fn animate(commands: &Self::Parsed, params: &Params, recorder: &mut Recorder) -> Result<()> {
+        ensure!(params.part2_knots > 0, "A rope needs at least one knot");
+
+        let view = head_bounds(commands);
+        let mut rope = Rope::new(params.part2_knots);
+        recorder.record_with(|| rope.frame(view));
+
+        for command in commands {
+            for _ in 0..command.count {
+                rope.step(command.direction);
+                recorder.record_with(|| rope.frame(view));
+            }
+        }
+
+        recorder.finish_with(|| rope.frame(view));
+
+        Ok(())
+    }
+}
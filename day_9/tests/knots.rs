@@ -0,0 +1,61 @@
+use day_9::{Params, Rope};
+use utils::Solver;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+fn visits(input: &str, knots: usize) -> usize {
+    let commands = Rope::parse(input).unwrap();
+    let params = Params {
+        part1_knots: knots,
+        ..Params::default()
+    };
+
+    Rope::part1(&commands, &params).unwrap()
+}
+
+#[test]
+fn a_rope_of_one_knot_visits_every_position_of_the_head() {
+    assert_eq!(visits("R 4\nU 2\n", 1), 7);
+    // the head comes back to where it started
+    assert_eq!(visits("R 2\nL 2\n", 1), 3);
+}
+
+#[test]
+fn longer_ropes_follow_the_head() {
+    assert_eq!(visits(EXAMPLE, 2), 13);
+    assert_eq!(visits(EXAMPLE, 10), 1);
+
+    let commands = Rope::parse(EXAMPLE).unwrap();
+    let params = Params {
+        part1_knots: 0,
+        ..Params::default()
+    };
+    assert!(Rope::part1(&commands, &params).is_err());
+}
+
+#[test]
+fn part_2_is_printed_when_part_1_fails() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_day_9"))
+        .args(["--input-str", "R 4\nU 2\n", "--format", "json"])
+        .args(["--param", "part1_knots=0"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let records: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records.len(), 1);
+    assert_eq!(
+        (&records[0]["part"], &records[0]["answer"]),
+        (&2.into(), &"1".into())
+    );
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Part 1 failed: A rope needs at least one knot"),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,222 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use serde::Deserialize;
+
+use crate::{day_input_path, Day, RawParams};
+
+/// Name of the puzzle input of each day, `day_N/input.txt` unless the manifest says otherwise.
+pub const REAL_INPUT: &str = "real";
+
+/// Known answers and the inputs they belong to, read from an `answers.toml` file. Besides the
+/// [`REAL_INPUT`], a day can have more inputs, like the examples of the puzzle description, each
+/// with a name, a path and the [`Solver::Params`](crate::Solver::Params) it needs. Answers are
+/// listed with one `[[answer]]` table per part and input:
+///
+/// ```toml
+/// [[input]]
+/// day = 15
+/// name = "example"
+/// path = "day_15/example.txt"
+/// params = { row = 10, bound = 20 }
+///
+/// [[answer]]
+/// day = 15
+/// part = 1
+/// input = "example"
+/// expected = "26"
+/// ```
+///
+/// Input paths are relative to the manifest.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Manifest {
+    #[serde(rename = "input", default)]
+    pub inputs: Vec<NamedInput>,
+    #[serde(rename = "answer", default)]
+    pub answers: Vec<Expected>,
+    /// Directory of the manifest, where the real inputs are found.
+    #[serde(skip)]
+    root: PathBuf,
+}
+
+/// An input of a day, with the parameters to solve it with.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct NamedInput {
+    pub day: u8,
+    pub name: String,
+    pub path: PathBuf,
+    #[serde(default)]
+    pub params: RawParams,
+}
+
+impl NamedInput {
+    pub fn read(&self) -> Result<String> {
+        crate::read_file(&self.path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    /// Name of the input.
+    pub input: String,
+    pub expected: String,
+}
+
+/// An entry of the manifest that did not produce its expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub expected: String,
+    /// The answer the solver gave, or why it did not give one.
+    pub found: Result<String, String>,
+}
+
+/// Outcome of checking a [`Manifest`] against the solvers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub checked: usize,
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not open file {}", path.display()))?;
+
+        let mut manifest: Manifest = toml::from_str(&content)
+            .with_context(|| format!("Invalid answers manifest {}", path.display()))?;
+
+        manifest.root = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        for input in &mut manifest.inputs {
+            input.path = manifest.root.join(&input.path);
+        }
+
+        for (idx, input) in manifest.inputs.iter().enumerate() {
+            ensure!(
+                !manifest.inputs[..idx]
+                    .iter()
+                    .any(|other| other.day == input.day && other.name == input.name),
+                "Day {} has more than one input named {} in {}",
+                input.day,
+                input.name,
+                path.display()
+            );
+        }
+
+        Ok(manifest)
+    }
+
+    /// The input of `day` called `name`. The [`REAL_INPUT`] is `day_N/input.txt` next to the
+    /// manifest, without parameters, unless the manifest declares it.
+    pub fn input(&self, day: u8, name: &str) -> Result<NamedInput> {
+        let declared = self
+            .inputs
+            .iter()
+            .find(|input| input.day == day && input.name == name);
+
+        match declared {
+            Some(input) => Ok(input.clone()),
+            None if name == REAL_INPUT => Ok(NamedInput {
+                day,
+                name: name.to_string(),
+                path: day_input_path(&self.root, day),
+                params: RawParams::default(),
+            }),
+            None => bail!("Day {day} has no input named {name}"),
+        }
+    }
+
+    /// Runs every registered input through the matching day in `days` and compares the answers.
+    /// Each input is solved only once, no matter how many parts are registered for it.
+    pub fn check(&self, days: &[Day]) -> Report {
+        let mut solved: BTreeMap<(u8, &str), Solved> = BTreeMap::new();
+        let mut report = Report::default();
+
+        for answer in &self.answers {
+            let answers = solved
+                .entry((answer.day, answer.input.as_str()))
+                .or_insert_with(|| self.solve(days, answer.day, &answer.input));
+
+            let found = match (answers, answer.part) {
+                (Err(err), _) => Err(err.clone()),
+                (Ok([part1, _]), 1) => part1.clone(),
+                (Ok([_, part2]), 2) => part2.clone(),
+                (Ok(_), part) => Err(format!("There is no part {part}")),
+            };
+
+            report.checked += 1;
+
+            if found.as_ref() != Ok(&answer.expected) {
+                report.mismatches.push(Mismatch {
+                    day: answer.day,
+                    part: answer.part,
+                    input: answer.input.clone(),
+                    expected: answer.expected.clone(),
+                    found,
+                });
+            }
+        }
+
+        report
+    }
+
+    fn solve(&self, days: &[Day], number: u8, name: &str) -> Solved {
+        let day = days
+            .iter()
+            .find(|day| day.number == number)
+            .ok_or_else(|| format!("Day {number} is not solved yet"))?;
+
+        let solve = || {
+            let input = self.input(number, name)?;
+            day.solve(&input.read()?, &input.params)
+        };
+        let answers = solve().map_err(|err| format!("{err:#}"))?;
+
+        Ok([answers.part1, answers.part2].map(|answer| answer.map_err(|err| format!("{err:#}"))))
+    }
+}
+
+/// Answers to both parts of one input, or why the input could not be solved at all.
+type Solved = Result<[Result<String, String>; 2], String>;
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for Mismatch {
+            day,
+            part,
+            input,
+            expected,
+            found,
+        } in &self.mismatches
+        {
+            writeln!(f, "day {day} part {part} ({input}):")?;
+            writeln!(f, "  expected: {expected}")?;
+
+            match found {
+                Ok(found) => writeln!(f, "  found:    {found}")?,
+                Err(err) => writeln!(f, "  error:    {err}")?,
+            }
+        }
+
+        write!(
+            f,
+            "{} of {} answers matched",
+            self.checked - self.mismatches.len(),
+            self.checked
+        )
+    }
+}
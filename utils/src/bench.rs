@@ -0,0 +1,114 @@
+use std::{path::Path, time::Duration};
+
+use anyhow::{ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{timed, trace, Solver};
+
+/// Summary of the time a stage took over several runs, in nanoseconds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Summarizes `samples`, which must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        // nearest-rank percentile
+        let percentile = |p: usize| nanos(samples[(samples.len() * p).div_ceil(100).max(1) - 1]);
+
+        Self {
+            min_ns: percentile(0),
+            median_ns: percentile(50),
+            p95_ns: percentile(95),
+            max_ns: percentile(100),
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn p95(&self) -> Duration {
+        Duration::from_nanos(self.p95_ns)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_ns)
+    }
+}
+
+/// Whole nanoseconds of `duration`, saturating after roughly 584 years.
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Timing statistics of every stage of one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bench {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Bench {
+    /// Stages in the order they run, with their names.
+    pub fn stages(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+/// Solves `input` `runs` times, timing parse, part 1 and part 2 separately. Fails if any stage
+/// fails, as there is nothing meaningful to time then.
+pub fn bench<S: Solver>(input: &str, params: &S::Params, runs: usize) -> Result<Bench> {
+    ensure!(runs > 0, "Need at least one run to benchmark");
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let (parsed, elapsed) = timed(|| trace::parse::<S>(input));
+        let parsed = parsed?;
+        parse.push(elapsed);
+
+        let (answer, elapsed) = timed(|| trace::part1::<S>(&parsed, params));
+        answer.context("Part 1 failed")?;
+        part1.push(elapsed);
+
+        let (answer, elapsed) = timed(|| trace::part2::<S>(&parsed, params));
+        answer.context("Part 2 failed")?;
+        part2.push(elapsed);
+    }
+
+    Ok(Bench {
+        day: S::DAY,
+        runs,
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_samples(part1),
+        part2: Stats::from_samples(part2),
+    })
+}
+
+/// Writes `results` as a JSON array to `path`.
+pub fn write_benches(path: &Path, results: &[Bench]) -> Result<()> {
+    let json = serde_json::to_string_pretty(results)?;
+
+    std::fs::write(path, json + "\n")
+        .with_context(|| format!("Could not write file {}", path.display()))
+}
@@ -0,0 +1,271 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+
+use crate::{workspace_root, Bench, InputSource, Manifest, Overrides, Param, RawParams};
+
+/// Command line arguments shared by all per-day binaries.
+#[derive(Debug, Clone, Parser)]
+#[command(about = "Solves one day of Advent of Code 2022")]
+pub struct Args {
+    /// Input file, or `-` to read from stdin. Defaults to the day's `input.txt`
+    #[arg(conflicts_with = "input_str")]
+    pub input: Option<PathBuf>,
+
+    /// Use the given string as the input instead of reading a file
+    #[arg(long, value_name = "TEXT")]
+    pub input_str: Option<String>,
+
+    /// Solve an input declared in `answers.toml`, like `example`, with its parameters
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["input", "input_str"])]
+    pub named: Option<String>,
+
+    /// Which part to solve
+    #[arg(long, value_enum, default_value_t = Parts::Both)]
+    pub part: Parts,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Print only the answers: no labels in text output and no header in CSV output
+    #[arg(long, short)]
+    pub quiet: bool,
+
+    /// Instead of printing the answers, solve the input this many times and print timing
+    /// statistics for every stage
+    #[arg(long, value_name = "RUNS")]
+    pub bench: Option<usize>,
+
+    /// Also write the benchmark results as JSON to this file
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    pub bench_output: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub params: ParamArgs,
+}
+
+/// Command line options overriding the parameters of the solvers.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct ParamArgs {
+    /// TOML file with parameters for each day, in tables like `[day_15]`
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Set a parameter of the solver, e.g. `--param row=10`, or `--param day_15.row=10` for a
+    /// single day. Takes precedence over the config file and can be repeated
+    #[arg(long = "param", value_name = "KEY=VALUE")]
+    pub params: Vec<Param>,
+}
+
+impl ParamArgs {
+    pub fn overrides(&self) -> Result<Overrides> {
+        let mut overrides = match &self.config {
+            Some(path) => Overrides::load(path)?,
+            None => Overrides::default(),
+        };
+
+        for param in &self.params {
+            overrides.set(param.clone());
+        }
+
+        Ok(overrides)
+    }
+}
+
+impl Args {
+    /// Where to read the input of `day` from.
+    pub fn source(&self, day: u8) -> InputSource {
+        match (&self.input_str, &self.input) {
+            (Some(input), _) => InputSource::Inline(input.clone()),
+            (None, Some(path)) if path.as_os_str() == "-" => InputSource::Stdin,
+            (None, Some(path)) => InputSource::File(path.clone()),
+            (None, None) => InputSource::default_for(day),
+        }
+    }
+
+    /// Reads the input of `day` along with its parameters: those of the named input, if any,
+    /// with the overrides of the command line applied.
+    pub fn load(&self, day: u8) -> Result<(String, RawParams)> {
+        let (input, params) = match &self.named {
+            Some(name) => {
+                let input =
+                    Manifest::load(&workspace_root().join("answers.toml"))?.input(day, name)?;
+
+                (input.read()?, input.params)
+            }
+            None => (self.source(day).read()?, RawParams::default()),
+        };
+
+        Ok((input, self.params.overrides()?.apply(day, params)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Parts {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn includes(&self, part: u8) -> bool {
+        match self {
+            Parts::One => part == 1,
+            Parts::Two => part == 2,
+            Parts::Both => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Part 1: answer`, one line per part
+    Text,
+    /// One JSON object per line: `{"day", "part", "answer", "elapsed_ns"}`
+    Json,
+    /// `day,part,answer,elapsed_ns` rows with a header
+    Csv,
+}
+
+/// A single answer as it is printed in the machine-readable formats. `elapsed_ns` only covers
+/// solving the part, not parsing the input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed_ns: u128,
+}
+
+impl Record {
+    pub fn new(day: u8, part: u8, answer: impl Display, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            elapsed_ns: elapsed.as_nanos(),
+        }
+    }
+}
+
+/// Prints answers in the format selected on the command line.
+#[derive(Debug)]
+pub struct Printer<W> {
+    out: W,
+    format: Format,
+    quiet: bool,
+    header_written: bool,
+}
+
+impl Printer<io::Stdout> {
+    pub fn stdout(args: &Args) -> Self {
+        Self::new(io::stdout(), args.format, args.quiet)
+    }
+}
+
+impl<W: Write> Printer<W> {
+    pub fn new(out: W, format: Format, quiet: bool) -> Self {
+        Self {
+            out,
+            format,
+            quiet,
+            header_written: false,
+        }
+    }
+
+    pub fn print(&mut self, record: &Record) -> Result<()> {
+        let Record { part, answer, .. } = record;
+
+        match self.format {
+            Format::Text if self.quiet => writeln!(self.out, "{answer}")?,
+            Format::Text if answer.contains('\n') => writeln!(self.out, "Part {part}:\n{answer}")?,
+            Format::Text => writeln!(self.out, "Part {part}: {answer}")?,
+            Format::Json => {
+                serde_json::to_writer(&mut self.out, record)?;
+                writeln!(self.out)?;
+            }
+            Format::Csv => {
+                if !self.header_written && !self.quiet {
+                    writeln!(self.out, "day,part,answer,elapsed_ns")?;
+                }
+                self.header_written = true;
+
+                writeln!(
+                    self.out,
+                    "{},{},{},{}",
+                    record.day,
+                    part,
+                    csv_field(answer),
+                    record.elapsed_ns
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn print_bench(&mut self, bench: &Bench) -> Result<()> {
+        match self.format {
+            Format::Text => {
+                if !self.quiet {
+                    writeln!(self.out, "Day {}, {} runs:", bench.day, bench.runs)?;
+                }
+
+                for (stage, stats) in bench.stages() {
+                    writeln!(
+                        self.out,
+                        "{stage:<8} min {:>10}  median {:>10}  p95 {:>10}  max {:>10}",
+                        format!("{:.2?}", stats.min()),
+                        format!("{:.2?}", stats.median()),
+                        format!("{:.2?}", stats.p95()),
+                        format!("{:.2?}", stats.max()),
+                    )?;
+                }
+            }
+            Format::Json => {
+                serde_json::to_writer(&mut self.out, bench)?;
+                writeln!(self.out)?;
+            }
+            Format::Csv => {
+                if !self.header_written && !self.quiet {
+                    writeln!(self.out, "day,stage,runs,min_ns,median_ns,p95_ns,max_ns")?;
+                }
+                self.header_written = true;
+
+                for (stage, stats) in bench.stages() {
+                    writeln!(
+                        self.out,
+                        "{},{stage},{},{},{},{},{}",
+                        bench.day,
+                        bench.runs,
+                        stats.min_ns,
+                        stats.median_ns,
+                        stats.p95_ns,
+                        stats.max_ns
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Quotes `field` if it contains anything that would otherwise break the row apart.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,310 @@
+use std::{any::type_name, str::FromStr};
+
+use crate::ParseError;
+
+/// Types that can be read from a [`Cursor`]. Implementing this instead of parsing a `&str`
+/// directly means errors point at the exact line and column of the whole input.
+pub trait FromCursor: Sized {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError>;
+}
+
+/// A single character, e.g. a cell of a character map.
+impl FromCursor for char {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        cursor.char()
+    }
+}
+
+/// Parses all of `input` as `T`, failing if anything is left over. Meant for `FromStr` impls of
+/// types that implement [`FromCursor`].
+pub fn parse_str<T: FromCursor>(input: &str) -> Result<T, ParseError> {
+    let mut cursor = Cursor::new(input);
+    let value = cursor.parse()?;
+    cursor.finish()?;
+
+    Ok(value)
+}
+
+/// Parses every line of `input` as `T`, failing on blank lines or anything else left over.
+pub fn parse_lines<T: FromCursor>(input: &str) -> Result<Vec<T>, ParseError> {
+    let mut cursor = Cursor::new(input);
+    let items = cursor.lines(T::from_cursor)?;
+    cursor.finish()?;
+
+    Ok(items)
+}
+
+/// Position in the puzzle input, handing out pieces of it while keeping track of where they came
+/// from.
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    /// The whole input, including the parts that were already consumed.
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// The part of the input that was not consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    /// 1-based line of the current position.
+    pub fn line(&self) -> usize {
+        self.source[..self.pos].matches('\n').count() + 1
+    }
+
+    /// 1-based column of the current position, counted in characters.
+    pub fn column(&self) -> usize {
+        let consumed = &self.source[..self.pos];
+        let line_start = consumed.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+        consumed[line_start..].chars().count() + 1
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.pos == self.source.len()
+    }
+
+    pub fn is_at_line_end(&self) -> bool {
+        self.is_at_end() || self.rest().starts_with('\n') || self.rest().starts_with("\r\n")
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Error at the current position, showing the next token as what was found instead.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest();
+        let token = match rest.chars().next() {
+            None | Some('\n' | '\r') => &rest[..0],
+            Some(ch) if ch.is_whitespace() => &rest[..ch.len_utf8()],
+            Some(_) => &rest[..rest.find(char::is_whitespace).unwrap_or(rest.len())],
+        };
+
+        ParseError::at(self.source, token, expected)
+    }
+
+    /// Error pointing at `span`, which must be a slice of the input, e.g. a value returned by an
+    /// earlier call.
+    pub fn error_at(&self, span: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.source, span, expected)
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let taken = &self.source[self.pos..self.pos + len];
+        self.pos += len;
+
+        taken
+    }
+
+    /// Consumes characters as long as `pred` holds, possibly none.
+    pub fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let len = self
+            .rest()
+            .find(|ch| !pred(ch))
+            .unwrap_or(self.rest().len());
+
+        self.advance(len)
+    }
+
+    /// Consumes everything up to, but not including, the end of the current line.
+    pub fn rest_of_line(&mut self) -> &'a str {
+        let line = self.take_while(|ch| ch != '\n');
+
+        match line.strip_suffix('\r') {
+            Some(line) => {
+                self.pos -= 1;
+                line
+            }
+            None => line,
+        }
+    }
+
+    /// Consumes `literal` if the input continues with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.advance(literal.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            return Ok(());
+        }
+
+        // point at the word that differs, not at whitespace both have in common
+        let mut at = *self;
+        for expected in literal.chars().take_while(|ch| *ch == ' ') {
+            if !at.eat(&expected.to_string()) {
+                break;
+            }
+        }
+
+        Err(at.error(format!("`{}`", literal.trim())))
+    }
+
+    /// Skips spaces and tabs, but not line breaks.
+    pub fn spaces(&mut self) {
+        self.take_while(|ch| ch == ' ' || ch == '\t');
+    }
+
+    /// Consumes a single character.
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        self.satisfy("any character", |_| true)
+    }
+
+    /// Consumes a single character if it matches `pred`.
+    pub fn satisfy(
+        &mut self,
+        expected: &str,
+        pred: impl Fn(char) -> bool,
+    ) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(ch) if ch != '\n' && pred(ch) => {
+                self.advance(ch.len_utf8());
+                Ok(ch)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    /// Consumes a single decimal digit.
+    pub fn digit(&mut self) -> Result<u32, ParseError> {
+        let digit = self.satisfy("digit", |ch| ch.is_ascii_digit())?;
+
+        Ok(digit as u32 - '0' as u32)
+    }
+
+    /// Consumes a non-empty run of letters, digits and underscores.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        match self.take_while(|ch| ch.is_alphanumeric() || ch == '_') {
+            "" => Err(self.error("word")),
+            word => Ok(word),
+        }
+    }
+
+    fn number<T: FromStr>(&mut self, start: usize, expected: &str) -> Result<T, ParseError> {
+        let digits = self.take_while(|ch| ch.is_ascii_digit());
+
+        if digits.is_empty() {
+            self.pos = start;
+            return Err(self.error(expected));
+        }
+
+        let number = &self.source[start..self.pos];
+        number.parse().map_err(|_| {
+            let expected = format!("{expected} that fits into {}", type_name::<T>());
+            self.error_at(number, expected)
+        })
+    }
+
+    /// Consumes an integer made of decimal digits only.
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.pos;
+
+        self.number(start, "unsigned integer")
+    }
+
+    /// Consumes an integer with an optional leading `-` or `+`.
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.pos;
+        let _ = self.eat("-") || self.eat("+");
+
+        self.number(start, "integer")
+    }
+
+    /// Consumes a `key=value` pair and parses the value, e.g. `x=-2`.
+    pub fn field<T: FromStr>(&mut self, key: &str) -> Result<T, ParseError> {
+        self.literal(key)?;
+        self.literal("=")?;
+
+        let value = self.take_while(|ch| ch.is_alphanumeric() || matches!(ch, '-' | '+' | '_'));
+        value
+            .parse()
+            .map_err(|_| self.error_at(value, format!("value for `{key}`")))
+    }
+
+    pub fn parse<T: FromCursor>(&mut self) -> Result<T, ParseError> {
+        T::from_cursor(self)
+    }
+
+    /// Consumes a line break, or succeeds without consuming anything at the end of the input.
+    pub fn line_end(&mut self) -> Result<(), ParseError> {
+        if self.is_at_end() || self.eat("\n") || self.eat("\r\n") {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    /// Succeeds only if all of the input was consumed.
+    pub fn finish(&mut self) -> Result<(), ParseError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of input"))
+        }
+    }
+
+    /// Parses one or more items separated by `separator`.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Parses one item per line until the end of the input or a blank line, which is left for the
+    /// caller to consume.
+    pub fn lines<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = Vec::new();
+
+        while !self.is_at_line_end() {
+            items.push(item(self)?);
+            self.line_end()?;
+        }
+
+        Ok(items)
+    }
+
+    /// Parses blocks of lines separated by a single blank line, until the end of the input.
+    pub fn blocks<T>(
+        &mut self,
+        mut block: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut blocks = Vec::new();
+
+        while !self.is_at_end() {
+            blocks.push(block(self)?);
+
+            if !self.is_at_end() {
+                self.line_end()?;
+            }
+        }
+
+        Ok(blocks)
+    }
+}
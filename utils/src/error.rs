@@ -0,0 +1,164 @@
+use std::{error::Error, fmt::Display};
+
+/// Error produced when the puzzle input does not match the expected format. Records where in the
+/// input the problem is, what was expected there and what was found instead, and renders the
+/// offending line with the span underlined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    expected: String,
+    found: String,
+    position: Option<Position>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Position {
+    offset: usize,
+    line: usize,
+    column: usize,
+    snippet: String,
+    end_of_input: bool,
+}
+
+impl ParseError {
+    /// Creates an error without any location information.
+    pub fn new(expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self {
+            expected: expected.into(),
+            found: found.into(),
+            position: None,
+        }
+    }
+
+    /// Creates an error for `span`, which should be a slice of `source`. Line and column are
+    /// calculated relative to the start of `source`, see [`ParseError::at_line`] for parsers that
+    /// only see a single line of the input.
+    pub fn at(source: &str, span: &str, expected: impl Into<String>) -> Self {
+        let error = Self::new(expected, span);
+
+        match offset_of(source, span) {
+            Some(offset) => error.with_offset(source, offset),
+            None => error,
+        }
+    }
+
+    /// Creates an error pointing right after the end of `source`, for input that stops too early.
+    pub fn at_end(source: &str, expected: impl Into<String>) -> Self {
+        Self::new(expected, "").with_offset(source, source.len())
+    }
+
+    fn with_offset(mut self, source: &str, offset: usize) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        let line_end = source[offset..]
+            .find('\n')
+            .map(|idx| offset + idx)
+            .unwrap_or(source.len());
+
+        self.position = Some(Position {
+            offset,
+            line: before.matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            snippet: source[line_start..line_end].trim_end_matches('\r').into(),
+            end_of_input: offset == source.len(),
+        });
+
+        self
+    }
+
+    /// Re-anchors an error that was created while parsing `span` so that it points into `source`,
+    /// which `span` is a slice of. Used when a parser delegates part of its input to another one.
+    pub fn within(self, source: &str, span: &str) -> Self {
+        let Some(span_offset) = offset_of(source, span) else {
+            return self;
+        };
+
+        let offset = match &self.position {
+            Some(position) => span_offset + position.offset,
+            None => span_offset,
+        };
+
+        self.with_offset(source, offset)
+    }
+
+    /// Moves the error to the given 1-based line, for errors created from a source that started at
+    /// that line of the whole input.
+    pub fn at_line(mut self, line: usize) -> Self {
+        if let Some(position) = self.position.as_mut() {
+            position.line += line.saturating_sub(1);
+        }
+
+        self
+    }
+
+    /// 1-based line number of the error, if known.
+    pub fn line(&self) -> Option<usize> {
+        self.position.as_ref().map(|position| position.line)
+    }
+
+    /// 1-based column of the error, counted in characters, if known.
+    pub fn column(&self) -> Option<usize> {
+        self.position.as_ref().map(|position| position.column)
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+fn offset_of(source: &str, span: &str) -> Option<usize> {
+    let start = source.as_ptr() as usize;
+    let span_start = span.as_ptr() as usize;
+
+    if span_start >= start && span_start + span.len() <= start + source.len() {
+        Some(span_start - start)
+    } else {
+        // span is not borrowed from source, e.g. it was trimmed into a new String
+        source.find(span)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let end_of_input = self
+            .position
+            .as_ref()
+            .map(|position| position.end_of_input)
+            .unwrap_or(true);
+
+        match self.found.as_str() {
+            "" if end_of_input => write!(f, "expected {}, found end of input", self.expected)?,
+            "" => write!(f, "expected {}, found end of line", self.expected)?,
+            found => write!(f, "expected {}, found `{found}`", self.expected)?,
+        }
+
+        if let Some(Position {
+            line,
+            column,
+            snippet,
+            ..
+        }) = &self.position
+        {
+            let gutter = line.to_string().len();
+            let underline = "^".repeat(self.found.chars().count().max(1));
+
+            write!(f, " at line {line}, column {column}")?;
+            write!(f, "\n{:gutter$} |", "")?;
+            write!(f, "\n{line} | {snippet}")?;
+            write!(
+                f,
+                "\n{:gutter$} | {:>width$}",
+                "",
+                underline,
+                width = column - 1 + underline.len()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
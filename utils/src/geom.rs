@@ -0,0 +1,444 @@
+//! Points, directions and rectangles on the integer plane. `y` grows downwards, as in the puzzle
+//! maps, so [`Direction::Up`] decreases it.
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::{Cursor, FromCursor, ParseError};
+
+/// Integer type usable as a coordinate. Unsigned coordinates simply have no points below zero.
+pub trait Coord: Copy + Ord + Hash + Debug {
+    /// The coordinate as an `i128`, which holds any value of the coordinate types without loss.
+    fn to_i128(self) -> i128;
+
+    /// `None` if `value` does not fit into `Self`.
+    fn from_i128(value: i128) -> Option<Self>;
+}
+
+macro_rules! impl_coord {
+    ($($ty:ty),*) => {
+        $(
+            impl Coord for $ty {
+                fn to_i128(self) -> i128 {
+                    // none of the coordinate types is wider than 64 bits
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Option<Self> {
+                    value.try_into().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(usize, isize, u32, i32, u64, i64);
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting with up.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Change in `x` and `y` when taking one step.
+    pub fn step(self) -> (i64, i64) {
+        Direction8::from(self).step()
+    }
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse_str(s)
+    }
+}
+
+impl FromCursor for Direction {
+    /// Reads either a letter (`U`, `R`, `D`, `L`) or an arrow (`^`, `>`, `v`, `<`).
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        let direction =
+            match cursor.satisfy("direction (U, R, D or L)", |ch| "URDL^>v<".contains(ch))? {
+                'U' | '^' => Self::Up,
+                'R' | '>' => Self::Right,
+                'D' | 'v' => Self::Down,
+                _ => Self::Left,
+            };
+
+        Ok(direction)
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting with up.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Change in `x` and `y` when taking one step.
+    pub fn step(self) -> (i64, i64) {
+        match self {
+            Self::Up => (0, -1),
+            Self::UpRight => (1, -1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, 1),
+            Self::Down => (0, 1),
+            Self::DownLeft => (-1, 1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, -1),
+        }
+    }
+
+    /// The direction after an eighth turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction after an eighth turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::Up,
+            Direction::Right => Self::Right,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<C = usize> {
+    pub x: C,
+    pub y: C,
+}
+
+impl<C: Coord> Point<C> {
+    pub fn new(x: C, y: C) -> Self {
+        Self { x, y }
+    }
+
+    /// The point `dx`, `dy` away, unless that is not representable in `C`.
+    pub fn offset(self, (dx, dy): (i64, i64)) -> Option<Self> {
+        Some(Self {
+            x: C::from_i128(self.x.to_i128() + i128::from(dx))?,
+            y: C::from_i128(self.y.to_i128() + i128::from(dy))?,
+        })
+    }
+
+    /// The neighbour in `direction`, unless that is not representable in `C`.
+    pub fn step(self, direction: impl Into<Direction8>) -> Option<Self> {
+        self.offset(direction.into().step())
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+
+    /// Number of orthogonal steps needed to reach `other`, or `usize::MAX` if there are more.
+    pub fn manhattan(self, other: Self) -> usize {
+        let (dx, dy) = self.abs_diff(other);
+
+        saturate(dx + dy)
+    }
+
+    /// Number of steps needed to reach `other` when diagonal steps are allowed too, or
+    /// `usize::MAX` if there are more.
+    pub fn chebyshev(self, other: Self) -> usize {
+        let (dx, dy) = self.abs_diff(other);
+
+        saturate(dx.max(dy))
+    }
+
+    fn abs_diff(self, other: Self) -> (u128, u128) {
+        (
+            self.x.to_i128().abs_diff(other.x.to_i128()),
+            self.y.to_i128().abs_diff(other.y.to_i128()),
+        )
+    }
+
+    /// The sign of both coordinates, i.e. the single step (possibly diagonal) that moves
+    /// closest towards this point when used as a difference of two points.
+    pub fn signum(self) -> Self {
+        let signum =
+            |value: C| C::from_i128(value.to_i128().signum()).expect("sign of a coordinate");
+
+        Self::new(signum(self.x), signum(self.y))
+    }
+}
+
+impl<C> From<(C, C)> for Point<C> {
+    fn from((x, y): (C, C)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<C: Add<Output = C>> Add for Point<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<C: Sub<Output = C>> Sub for Point<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<C: AddAssign> AddAssign for Point<C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<C: SubAssign> SubAssign for Point<C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<C: Mul<Output = C> + Copy> Mul<C> for Point<C> {
+    type Output = Self;
+
+    fn mul(self, rhs: C) -> Self {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<C: Neg<Output = C>> Neg for Point<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<C: Display> Display for Point<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<C: Coord + FromStr> FromStr for Point<C> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse_str(s)
+    }
+}
+
+impl<C: Coord + FromStr> FromCursor for Point<C> {
+    /// Reads either `x,y` or `x=..., y=...`, with optional spaces after the comma.
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        if cursor.peek() == Some('x') {
+            let x = cursor.field("x")?;
+            cursor.literal(",")?;
+            cursor.spaces();
+            let y = cursor.field("y")?;
+
+            return Ok(Self { x, y });
+        }
+
+        let x = cursor.signed()?;
+        cursor.literal(",")?;
+        cursor.spaces();
+        let y = cursor.signed()?;
+
+        Ok(Self { x, y })
+    }
+}
+
+impl<'de, C: Coord + FromStr + Deserialize<'de>> Deserialize<'de> for Point<C> {
+    /// Accepts the text [`Point::from_str`] reads, like `"500,0"`, or a map like
+    /// `{ x = 500, y = 0 }`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<C> {
+            Text(String),
+            Fields { x: C, y: C },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Text(text) => text.parse().map_err(de::Error::custom),
+            Repr::Fields { x, y } => Ok(Self { x, y }),
+        }
+    }
+}
+
+/// Smallest rectangle containing a set of points. Both corners are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<C = usize> {
+    pub min: Point<C>,
+    pub max: Point<C>,
+}
+
+impl<C: Coord> Bounds<C> {
+    pub fn new(min: Point<C>, max: Point<C>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn of_point(point: Point<C>) -> Self {
+        Self::new(point, point)
+    }
+
+    /// Bounds of all `points`, `None` if there are none.
+    pub fn of_points(points: impl IntoIterator<Item = Point<C>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::of_point(points.next()?);
+
+        for point in points {
+            bounds.extend(point);
+        }
+
+        Some(bounds)
+    }
+
+    /// Grows the bounds just enough to contain `point`.
+    pub fn extend(&mut self, point: Point<C>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    /// Smallest bounds containing both.
+    pub fn union(self, other: Self) -> Self {
+        let mut bounds = self;
+        bounds.extend(other.min);
+        bounds.extend(other.max);
+
+        bounds
+    }
+
+    /// Points contained in both, `None` if they do not overlap.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+
+        (min.x <= max.x && min.y <= max.y).then_some(Self::new(min, max))
+    }
+
+    pub fn contains(&self, point: Point<C>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Number of columns, or `usize::MAX` if there are more.
+    pub fn width(&self) -> usize {
+        saturate(self.max.x.to_i128().abs_diff(self.min.x.to_i128()) + 1)
+    }
+
+    /// Number of rows, or `usize::MAX` if there are more.
+    pub fn height(&self) -> usize {
+        saturate(self.max.y.to_i128().abs_diff(self.min.y.to_i128()) + 1)
+    }
+
+    /// The four corners, clockwise starting with the top left one.
+    pub fn corners(&self) -> [Point<C>; 4] {
+        [
+            self.min,
+            Point::new(self.max.x, self.min.y),
+            self.max,
+            Point::new(self.min.x, self.max.y),
+        ]
+    }
+
+    /// All points inside, row by row.
+    pub fn points(self) -> impl Iterator<Item = Point<C>> {
+        let (min_x, max_x) = (self.min.x.to_i128(), self.max.x.to_i128());
+        let (min_y, max_y) = (self.min.y.to_i128(), self.max.y.to_i128());
+
+        (min_y..=max_y).flat_map(move |y| {
+            (min_x..=max_x)
+                .filter_map(move |x| Some(Point::new(C::from_i128(x)?, C::from_i128(y)?)))
+        })
+    }
+}
+
+/// `value` as a `usize`, or `usize::MAX` if it is larger.
+fn saturate(value: u128) -> usize {
+    value.try_into().unwrap_or(usize::MAX)
+}
@@ -0,0 +1,390 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Bounds, Coord, Cursor, Direction8, FromCursor, ParseError, Point};
+
+/// Operations shared by the dense [`Grid`] and the [`SparseGrid`]. A cell either holds a value
+/// or is missing, which for a dense grid only happens outside of its bounds.
+pub trait Cells<T> {
+    type Coord: Coord;
+
+    fn get(&self, point: Point<Self::Coord>) -> Option<&T>;
+
+    /// Bounds of all cells holding a value, `None` if there are none.
+    fn bounds(&self) -> Option<Bounds<Self::Coord>>;
+
+    fn contains(&self, point: Point<Self::Coord>) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// Orthogonal neighbours of `point` that hold a value.
+    fn neighbours4<'a>(
+        &'a self,
+        point: Point<Self::Coord>,
+    ) -> impl Iterator<Item = (Point<Self::Coord>, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        point
+            .neighbours4()
+            .filter_map(|point| Some((point, self.get(point)?)))
+    }
+
+    /// Orthogonal and diagonal neighbours of `point` that hold a value.
+    fn neighbours8<'a>(
+        &'a self,
+        point: Point<Self::Coord>,
+    ) -> impl Iterator<Item = (Point<Self::Coord>, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        point
+            .neighbours8()
+            .filter_map(|point| Some((point, self.get(point)?)))
+    }
+
+    /// Cells hit when walking from `start` (excluded) in `direction`, until leaving the bounds.
+    fn ray<'a>(
+        &'a self,
+        start: Point<Self::Coord>,
+        direction: impl Into<Direction8>,
+    ) -> impl Iterator<Item = (Point<Self::Coord>, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        let bounds = self.bounds();
+        let step = direction.into().step();
+
+        std::iter::successors(start.offset(step), move |point| point.offset(step))
+            .take_while(move |point| bounds.is_some_and(|bounds| bounds.contains(*point)))
+            .filter_map(|point| Some((point, self.get(point)?)))
+    }
+
+    /// Cells of row `y`, left to right.
+    fn row<'a>(&'a self, y: Self::Coord) -> impl Iterator<Item = (Point<Self::Coord>, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.bounds()
+            .into_iter()
+            .flat_map(move |bounds| {
+                Bounds::new(Point::new(bounds.min.x, y), Point::new(bounds.max.x, y)).points()
+            })
+            .filter_map(|point| Some((point, self.get(point)?)))
+    }
+
+    /// Cells of column `x`, top to bottom.
+    fn column<'a>(
+        &'a self,
+        x: Self::Coord,
+    ) -> impl Iterator<Item = (Point<Self::Coord>, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.bounds()
+            .into_iter()
+            .flat_map(move |bounds| {
+                Bounds::new(Point::new(x, bounds.min.y), Point::new(x, bounds.max.y)).points()
+            })
+            .filter_map(|point| Some((point, self.get(point)?)))
+    }
+
+    /// Draws the bounds row by row, one character per cell.
+    fn render(&self, cell: impl Fn(Point<Self::Coord>, Option<&T>) -> char) -> String {
+        let Some(bounds) = self.bounds() else {
+            return String::new();
+        };
+
+        let mut res = String::with_capacity((bounds.width() + 1) * bounds.height());
+
+        for point in bounds.points() {
+            if point.x == bounds.min.x && point != bounds.min {
+                res.push('\n');
+            }
+
+            res.push(cell(point, self.get(point)));
+        }
+
+        res
+    }
+}
+
+/// Rectangular grid storing every cell, for maps that are filled completely.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T, C = usize> {
+    bounds: Bounds<C>,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T, C: Coord> Grid<T, C> {
+    /// Grid covering `bounds` with every cell set to `fill`.
+    pub fn new(bounds: Bounds<C>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            bounds,
+            width: bounds.width(),
+            cells: vec![fill; bounds.width() * bounds.height()],
+        }
+    }
+
+    /// Grid with its top left corner at `origin`. Fails if the rows differ in length or the grid
+    /// would not fit into `C`.
+    pub fn from_rows(origin: Point<C>, rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first()?.len();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        let max = origin.offset((width as i64 - 1, rows.len() as i64 - 1))?;
+
+        Some(Self {
+            bounds: Bounds::new(origin, max),
+            width,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Reads a character map, one cell per `cell` call, with rows ending at line breaks. Stops
+    /// at the end of the input or a blank line and fails on ragged rows.
+    pub fn parse_with(
+        cursor: &mut Cursor<'_>,
+        mut cell: impl FnMut(&mut Cursor<'_>) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let first_row = *cursor;
+
+        let rows = cursor.lines(|cursor| {
+            let mut row = Vec::new();
+
+            match width {
+                None => {
+                    while !cursor.is_at_line_end() {
+                        row.push(cell(cursor)?);
+                    }
+                    width = Some(row.len());
+                }
+                Some(width) => {
+                    for _ in 0..width {
+                        row.push(cell(cursor)?);
+                    }
+                }
+            }
+
+            Ok(row)
+        })?;
+
+        C::from_i128(0)
+            .and_then(|zero| Self::from_rows(Point::new(zero, zero), rows))
+            .ok_or_else(|| first_row.error("grid of at least one cell"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    fn offset(&self, point: Point<C>) -> Option<usize> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        let x = (point.x.to_i128() - self.bounds.min.x.to_i128()) as usize;
+        let y = (point.y.to_i128() - self.bounds.min.y.to_i128()) as usize;
+
+        Some(y * self.width + x)
+    }
+
+    fn point(&self, offset: usize) -> Point<C> {
+        let x = (offset % self.width) as i64;
+        let y = (offset / self.width) as i64;
+
+        self.bounds
+            .min
+            .offset((x, y))
+            .expect("grid bounds fit into the coordinate type")
+    }
+
+    pub fn get_mut(&mut self, point: Point<C>) -> Option<&mut T> {
+        let offset = self.offset(point)?;
+
+        self.cells.get_mut(offset)
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<C>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(offset, cell)| (self.point(offset), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point<C>, &mut T)> {
+        let (bounds, width) = (self.bounds, self.width);
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(offset, cell)| {
+                let point = bounds
+                    .min
+                    .offset(((offset % width) as i64, (offset / width) as i64))
+                    .expect("grid bounds fit into the coordinate type");
+
+                (point, cell)
+            })
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Rows as slices, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U, C> {
+        Grid {
+            bounds: self.bounds,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T, C: Coord> Cells<T> for Grid<T, C> {
+    type Coord = C;
+
+    fn get(&self, point: Point<C>) -> Option<&T> {
+        self.cells.get(self.offset(point)?)
+    }
+
+    fn bounds(&self) -> Option<Bounds<C>> {
+        Some(self.bounds)
+    }
+}
+
+impl<T, C: Coord> Index<Point<C>> for Grid<T, C> {
+    type Output = T;
+
+    fn index(&self, point: Point<C>) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T, C: Coord> IndexMut<Point<C>> for Grid<T, C> {
+    fn index_mut(&mut self, point: Point<C>) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T: FromCursor, C: Coord> FromCursor for Grid<T, C> {
+    fn from_cursor(cursor: &mut Cursor<'_>) -> Result<Self, ParseError> {
+        Self::parse_with(cursor, T::from_cursor)
+    }
+}
+
+/// Grid storing only the cells that were set, for large or unbounded maps with few interesting
+/// cells. The bounds grow with every insertion and never shrink.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SparseGrid<T, C = isize> {
+    // keyed by (y, x) so rows are contiguous
+    cells: BTreeMap<(C, C), T>,
+    bounds: Option<Bounds<C>>,
+}
+
+impl<T, C: Coord> Default for SparseGrid<T, C> {
+    fn default() -> Self {
+        Self {
+            cells: BTreeMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T, C: Coord> SparseGrid<T, C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the cell at `point`, returning its previous value.
+    pub fn insert(&mut self, point: Point<C>, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(point),
+            None => self.bounds = Some(Bounds::of_point(point)),
+        }
+
+        self.cells.insert((point.y, point.x), value)
+    }
+
+    pub fn remove(&mut self, point: Point<C>) -> Option<T> {
+        self.cells.remove(&(point.y, point.x))
+    }
+
+    pub fn get_mut(&mut self, point: Point<C>) -> Option<&mut T> {
+        self.cells.get_mut(&(point.y, point.x))
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// All set cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<C>, &T)> {
+        self.cells
+            .iter()
+            .map(|(&(y, x), cell)| (Point::new(x, y), cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+}
+
+impl<T, C: Coord> Cells<T> for SparseGrid<T, C> {
+    type Coord = C;
+
+    fn get(&self, point: Point<C>) -> Option<&T> {
+        self.cells.get(&(point.y, point.x))
+    }
+
+    fn bounds(&self) -> Option<Bounds<C>> {
+        self.bounds
+    }
+
+    fn row<'a>(&'a self, y: C) -> impl Iterator<Item = (Point<C>, &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        self.bounds
+            .into_iter()
+            .flat_map(move |bounds| self.cells.range((y, bounds.min.x)..=(y, bounds.max.x)))
+            .map(|(&(y, x), cell)| (Point::new(x, y), cell))
+    }
+}
+
+impl<T, C: Coord> FromIterator<(Point<C>, T)> for SparseGrid<T, C> {
+    fn from_iter<I: IntoIterator<Item = (Point<C>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+
+        grid
+    }
+}
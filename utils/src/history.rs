@@ -0,0 +1,203 @@
+use std::{
+    fs::OpenOptions,
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{Bench, RawParams, Stats};
+
+/// Benchmark result of one day, and what it was measured on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measured {
+    /// Name of the input, like `real` or `example`. Entries recorded before inputs were named
+    /// have an empty name.
+    #[serde(default)]
+    pub input: String,
+    /// Parameters the day was solved with.
+    #[serde(default, skip_serializing_if = "RawParams::is_empty")]
+    pub params: RawParams,
+    #[serde(flatten)]
+    pub bench: Bench,
+}
+
+impl Measured {
+    /// Whether both were measured on the same input with the same parameters.
+    pub fn comparable(&self, other: &Measured) -> bool {
+        self.bench.day == other.bench.day
+            && self.input == other.input
+            && self.params == other.params
+    }
+}
+
+/// Benchmark result of one day, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Identifies the run the result belongs to: the time it was recorded, in milliseconds since
+    /// the Unix epoch. Every day benchmarked in one go shares the same run.
+    pub run: u64,
+    /// Free-form name for the run, e.g. a commit hash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(flatten)]
+    pub measured: Measured,
+}
+
+/// All results of one run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub run: u64,
+    pub label: Option<String>,
+    pub benches: Vec<Measured>,
+}
+
+impl Run {
+    /// Whether `selector` names this run, either by its label or by its number.
+    pub fn matches(&self, selector: &str) -> bool {
+        self.label.as_deref() == Some(selector) || self.run.to_string() == selector
+    }
+
+    /// The result of this run that can be compared with `measured`.
+    fn comparable(&self, measured: &Measured) -> Option<&Measured> {
+        self.benches.iter().find(|bench| bench.comparable(measured))
+    }
+}
+
+/// Benchmark results over time, stored as one JSON object per line so runs can be appended
+/// without rewriting the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends `benches` as a new run and returns its number.
+    pub fn record(&self, benches: &[Measured], label: Option<&str>) -> Result<u64> {
+        let run = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis()
+            .try_into()
+            .unwrap_or(u64::MAX);
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Could not open file {}", self.path.display()))?;
+
+        let mut lines = String::new();
+        for bench in benches {
+            let entry = Entry {
+                run,
+                label: label.map(String::from),
+                measured: bench.clone(),
+            };
+
+            lines += &serde_json::to_string(&entry)?;
+            lines.push('\n');
+        }
+
+        file.write_all(lines.as_bytes())
+            .with_context(|| format!("Could not write file {}", self.path.display()))?;
+
+        Ok(run)
+    }
+
+    /// Reads all runs, oldest first. A missing file is an empty history.
+    pub fn runs(&self) -> Result<Vec<Run>> {
+        let file = match std::fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Could not open file {}", self.path.display()))
+            }
+        };
+
+        let mut runs: Vec<Run> = Vec::new();
+
+        for (idx, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let entry: Entry = serde_json::from_str(&line)
+                .with_context(|| format!("Invalid entry at {}:{}", self.path.display(), idx + 1))?;
+
+            match runs.last_mut() {
+                Some(last) if last.run == entry.run => last.benches.push(entry.measured),
+                _ => runs.push(Run {
+                    run: entry.run,
+                    label: entry.label,
+                    benches: vec![entry.measured],
+                }),
+            }
+        }
+
+        Ok(runs)
+    }
+}
+
+/// Change in the median time of one stage between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: &'static str,
+    pub baseline: Duration,
+    pub latest: Duration,
+    /// Relative change in percent, positive when the latest run is slower.
+    pub change: f64,
+    pub regression: bool,
+}
+
+/// Compares the median of every stage of the days present in both runs, measured on the same
+/// input with the same parameters. A stage regressed if it got slower by more than `threshold`
+/// percent.
+pub fn compare(baseline: &Run, latest: &Run, threshold: f64) -> Vec<Comparison> {
+    let mut comparisons = Vec::new();
+
+    for measured in &latest.benches {
+        let Some(base) = baseline.comparable(measured) else {
+            continue;
+        };
+        let (bench, base) = (&measured.bench, &base.bench);
+
+        for ((stage, latest), (_, baseline)) in bench.stages().into_iter().zip(base.stages()) {
+            let change = relative_change(baseline, latest);
+
+            comparisons.push(Comparison {
+                day: bench.day,
+                stage,
+                baseline: baseline.median(),
+                latest: latest.median(),
+                change,
+                regression: change > threshold,
+            });
+        }
+    }
+
+    comparisons
+}
+
+fn relative_change(baseline: Stats, latest: Stats) -> f64 {
+    let (baseline, latest) = (baseline.median_ns as f64, latest.median_ns as f64);
+
+    if baseline == 0.0 {
+        return 0.0;
+    }
+
+    (latest - baseline) / baseline * 100.0
+}
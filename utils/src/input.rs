@@ -0,0 +1,91 @@
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use flate2::read::MultiGzDecoder;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Standard input, selected with `-` on the command line.
+    Stdin,
+    /// The input itself, given with `--input-str`.
+    Inline(String),
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The `day_N/input.txt` file of the given day.
+    pub fn default_for(day: u8) -> Self {
+        Self::File(day_input_path(&workspace_root(), day))
+    }
+
+    /// Reads the whole input, decompressing it if it is gzip or zstd compressed.
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::Stdin => {
+                read_input(io::stdin().lock()).context("Could not read input from stdin")
+            }
+            Self::Inline(input) => Ok(input.clone()),
+            Self::File(path) => read_file(path),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "stdin"),
+            Self::Inline(_) => write!(f, "inline input"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Root of the workspace the day crates live in.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
+
+/// Path of the input of `day` below `root`, laid out like the workspace: `day_N/input.txt`.
+pub fn day_input_path(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day_{day}")).join("input.txt")
+}
+
+/// Reads the file at `path`, decompressing it if needed. The [`io::Error`] stays in the error
+/// chain, so callers can still check e.g. for [`io::ErrorKind::NotFound`].
+pub fn read_file(path: &Path) -> Result<String> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Could not open file {}", path.display()))?;
+
+    read_input(file).with_context(|| format!("Could not read file {}", path.display()))
+}
+
+fn read_input(mut reader: impl Read) -> Result<String> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    let bytes = if bytes.starts_with(GZIP_MAGIC) {
+        let mut decompressed = Vec::new();
+        MultiGzDecoder::new(&bytes[..])
+            .read_to_end(&mut decompressed)
+            .context("Input is not valid gzip")?;
+
+        decompressed
+    } else if bytes.starts_with(ZSTD_MAGIC) {
+        zstd::decode_all(&bytes[..]).context("Input is not valid zstd")?
+    } else {
+        bytes
+    };
+
+    String::from_utf8(bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err).into())
+}
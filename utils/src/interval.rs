@@ -0,0 +1,219 @@
+use std::ops::RangeInclusive;
+
+use crate::Coord;
+
+/// Set of integers stored as sorted, disjoint ranges. Overlapping or adjacent ranges are merged
+/// on insertion, so every set has exactly one representation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // sorted by start, with at least one missing value between two neighbouring ranges
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Coord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set, or `usize::MAX` if there are more.
+    pub fn len(&self) -> usize {
+        let len: u128 = self
+            .ranges
+            .iter()
+            .map(|&(start, end)| end.to_i128().abs_diff(start.to_i128()) + 1)
+            .sum();
+
+        len.try_into().unwrap_or(usize::MAX)
+    }
+
+    /// The ranges making up the set, in ascending order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The ranges of missing values between the smallest and the largest value, in ascending
+    /// order.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).filter_map(|pair| {
+            let start = T::from_i128(pair[0].1.to_i128() + 1)?;
+            let end = T::from_i128(pair[1].0.to_i128() - 1)?;
+
+            Some(start..=end)
+        })
+    }
+
+    /// Adds all values of `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut inserted = false;
+
+        for &(other_start, other_end) in &self.ranges {
+            if apart(other_end, start) {
+                ranges.push((other_start, other_end));
+            } else if apart(end, other_start) {
+                if !inserted {
+                    ranges.push((start, end));
+                    inserted = true;
+                }
+                ranges.push((other_start, other_end));
+            } else {
+                start = start.min(other_start);
+                end = end.max(other_end);
+            }
+        }
+
+        if !inserted {
+            ranges.push((start, end));
+        }
+
+        self.ranges = ranges;
+    }
+
+    /// Removes all values of `range`.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+
+        for &(other_start, other_end) in &self.ranges {
+            if other_end < start || other_start > end {
+                ranges.push((other_start, other_end));
+                continue;
+            }
+
+            // the values next to the removed range lie within `other`, so they are representable
+            if other_start < start {
+                let before = T::from_i128(start.to_i128() - 1).expect("value within the set");
+                ranges.push((other_start, before));
+            }
+            if other_end > end {
+                let after = T::from_i128(end.to_i128() + 1).expect("value within the set");
+                ranges.push((after, other_end));
+            }
+        }
+
+        self.ranges = ranges;
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|&(_, end)| end < value);
+
+        self.ranges
+            .get(idx)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Whether all values of `range` are in the set. Empty ranges are always contained.
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+
+        let idx = self
+            .ranges
+            .partition_point(|&(_, end)| end < *range.start());
+
+        self.ranges
+            .get(idx)
+            .is_some_and(|&(start, end)| start <= *range.start() && *range.end() <= end)
+    }
+
+    /// Whether all values of `other` are in this set.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.ranges().all(|range| self.contains_range(&range))
+    }
+
+    /// Whether the sets share at least one value.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.ranges());
+
+        union
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (self.ranges.iter(), other.ranges.iter());
+        let (mut a, mut b) = (left.next(), right.next());
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) = (a, b) {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                a = left.next();
+            } else {
+                b = right.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.ranges() {
+            difference.remove(range);
+        }
+
+        difference
+    }
+}
+
+/// Whether a range ending at `end` lies before one starting at `start` with a gap between them.
+fn apart<T: Coord>(end: T, start: T) -> bool {
+    end.to_i128().saturating_add(1) < start.to_i128()
+}
+
+impl<T: Coord> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+
+        set
+    }
+}
+
+impl<T: Coord> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Coord> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+
+        set
+    }
+}
@@ -1,37 +1,30 @@
-use std::{error::Error, fmt::Display};
-
-use anyhow::Result;
-
-#[derive(Debug)]
-pub struct StringError {
-    msg: String,
-}
-
-impl Display for StringError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.msg)
-    }
-}
-
-impl<T> From<T> for StringError
-where
-    T: Into<String>,
-{
-    fn from(input: T) -> Self {
-        Self { msg: input.into() }
-    }
-}
-
-impl Error for StringError {}
-
-pub fn read_file() -> Result<String> {
-    let mut args = std::env::args();
-
-    let file_name = args
-        .nth(1)
-        .ok_or_else(|| StringError::from("Please provide file path."))?;
-
-    std::fs::read_to_string(&file_name)
-        .map_err(|_| StringError::from(format!("Could not open file {file_name}")))
-        .map_err(anyhow::Error::new)
-}
+mod answers;
+mod bench;
+mod cli;
+mod cursor;
+mod error;
+pub mod geom;
+mod grid;
+mod history;
+mod input;
+mod interval;
+mod params;
+pub mod render;
+pub mod search;
+mod solver;
+mod trace;
+
+pub use answers::{Expected, Manifest, Mismatch, NamedInput, Report, REAL_INPUT};
+pub use bench::{bench, write_benches, Bench, Stats};
+pub use cli::{Args, Format, ParamArgs, Parts, Printer, Record};
+pub use cursor::{parse_lines, parse_str, Cursor, FromCursor};
+pub use error::ParseError;
+pub use geom::{Bounds, Coord, Direction, Direction8, Point};
+pub use grid::{Cells, Grid, SparseGrid};
+pub use history::{compare, Comparison, Entry, History, Measured, Run};
+pub use input::{day_input_path, read_file, workspace_root, InputSource};
+pub use interval::IntervalSet;
+pub use params::{Overrides, Param, RawParams};
+pub use render::{Animation, Color, Frame, Pixel, Recorder, Render};
+pub use solver::{print_answers, run, run_with, timed, Answers, Day, Solver, Timings};
+pub use trace::{init_tracing, LOG_VAR};
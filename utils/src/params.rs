@@ -0,0 +1,178 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path, str::FromStr};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Parameters of an input as they are written in the manifest, e.g. `{ row = 10, bound = 20 }`,
+/// before they are converted to the [`Solver::Params`](crate::Solver::Params) of a day.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RawParams(toml::Table);
+
+impl RawParams {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Sets `key`, replacing its value if it was already set.
+    pub fn set(&mut self, key: &str, value: toml::Value) {
+        self.0.insert(key.to_string(), value);
+    }
+
+    /// Sets every parameter of `other`, keeping the ones it does not mention.
+    pub fn merge(&mut self, other: &RawParams) {
+        for (key, value) in &other.0 {
+            self.set(key, value.clone());
+        }
+    }
+
+    /// Converts the parameters to `P`. Without any parameters this is `P::default()`; fields that
+    /// are left out keep their default if `P` is marked `#[serde(default)]`.
+    pub fn get<P: Default + DeserializeOwned>(&self) -> Result<P> {
+        if self.is_empty() {
+            return Ok(P::default());
+        }
+
+        toml::Value::Table(self.0.clone())
+            .try_into()
+            .with_context(|| format!("Invalid parameters {self}"))
+    }
+}
+
+impl Display for RawParams {
+    /// Writes the parameters as an inline table, like `{ row = 10, bound = 20 }`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<String> = self
+            .0
+            .iter()
+            .map(|(key, value)| format!("{key} = {value}"))
+            .collect();
+
+        write!(f, "{{ {} }}", params.join(", "))
+    }
+}
+
+/// A single parameter given on the command line as `key=value`, or as `day_N.key=value` for only
+/// one day. The value is read as TOML, like `10`, `[20, 60]` or `{ x = 500, y = 0 }`, and taken as
+/// a plain string if it is not valid TOML, so `start=AA` needs no quotes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    /// The day the parameter is for, or `None` for whichever days are solved.
+    pub day: Option<u8>,
+    pub key: String,
+    pub value: toml::Value,
+}
+
+impl FromStr for Param {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Expected a parameter like `key=value`, got: {s}"))?;
+
+        let (day, key) = match key.trim().split_once('.') {
+            Some((day, key)) if day.starts_with("day_") => {
+                let day = day["day_".len()..]
+                    .parse()
+                    .map_err(|_| anyhow!("Expected a day like `day_15.key=value`, got: {s}"))?;
+
+                (Some(day), key.trim())
+            }
+            _ => (None, key.trim()),
+        };
+
+        if key.is_empty() {
+            bail!("Missing the name of the parameter in: {s}");
+        }
+
+        let value = toml::from_str::<toml::Table>(&format!("value = {value}"))
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.to_string()));
+
+        Ok(Self {
+            day,
+            key: key.to_string(),
+            value,
+        })
+    }
+}
+
+/// Parameters set on top of the ones of the input, from a config file with a table per day and
+/// from [`Param`]s for one day or for every day solved:
+///
+/// ```toml
+/// [day_15]
+/// row = 10
+/// bound = 20
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overrides {
+    days: BTreeMap<u8, RawParams>,
+    all: RawParams,
+}
+
+impl Overrides {
+    /// Reads the `[day_N]` tables of a config file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not open file {}", path.display()))?;
+
+        let tables: BTreeMap<String, RawParams> = toml::from_str(&content)
+            .with_context(|| format!("Invalid config {}", path.display()))?;
+
+        let mut days = BTreeMap::new();
+        for (name, params) in tables {
+            let day = name
+                .strip_prefix("day_")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Expected tables like [day_15] in {}, got [{name}]",
+                        path.display()
+                    )
+                })?;
+
+            days.insert(day, params);
+        }
+
+        Ok(Self {
+            days,
+            all: RawParams::default(),
+        })
+    }
+
+    /// Sets `param` for its day, or for every day if it has none, over the config file.
+    pub fn set(&mut self, param: Param) {
+        let params = match param.day {
+            Some(day) => self.days.entry(day).or_default(),
+            None => &mut self.all,
+        };
+
+        params.set(&param.key, param.value);
+    }
+
+    /// Fails if a parameter is set for every day. Days refuse parameters they do not know, so
+    /// those only make sense when solving a single day.
+    pub fn ensure_scoped(&self) -> Result<()> {
+        if let Some(key) = self.all.0.keys().next() {
+            bail!(
+                "The parameter `{key}` is set for every day, write it as `day_N.{key}=...` to \
+                 solve more than one day"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// `params` of an input of `day` with the overrides applied.
+    pub fn apply(&self, day: u8, mut params: RawParams) -> RawParams {
+        if let Some(overrides) = self.days.get(&day) {
+            params.merge(overrides);
+        }
+
+        params.merge(&self.all);
+        params
+    }
+}
@@ -0,0 +1,435 @@
+//! Frames of a simulation, drawn one character per cell, that can be replayed in the terminal or
+//! exported as images.
+//!
+//! Days draw their state into a [`Frame`], usually through [`Render`], and emit it into a
+//! [`Recorder`] while they simulate. The recorded [`Animation`] is then played in color with
+//! [`Animation::play`] or written to a file with [`Animation::save`].
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+    fs,
+    io::Write,
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use anyhow::{bail, ensure, Context, Result};
+
+use crate::{Bounds, Cells, Coord, Point};
+
+/// A color in the terminal and in images.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const DARK_GREY: Color = Color::rgb(64, 64, 64);
+    pub const GREY: Color = Color::rgb(128, 128, 128);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const ORANGE: Color = Color::rgb(255, 140, 0);
+    pub const YELLOW: Color = Color::rgb(240, 200, 60);
+    pub const GREEN: Color = Color::rgb(60, 180, 75);
+    pub const DARK_GREEN: Color = Color::rgb(20, 70, 30);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const BROWN: Color = Color::rgb(140, 90, 50);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// The color `t` of the way from `self` to `other`, with `t` between 0 and 1.
+    pub fn blend(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+
+        Color::rgb(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+}
+
+/// A single cell of a [`Frame`]: the character shown in the terminal and its color, which fills
+/// the cell in images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pixel {
+    pub ch: char,
+    pub color: Color,
+}
+
+impl Pixel {
+    /// Empty space, black in images.
+    pub const BLANK: Pixel = Pixel::new(' ', Color::BLACK);
+
+    pub const fn new(ch: char, color: Color) -> Self {
+        Self { ch, color }
+    }
+}
+
+/// A picture of a simulation at one moment, `width` by `height` pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<Pixel>,
+}
+
+impl Frame {
+    /// A frame of blank pixels.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Pixel::BLANK; width * height],
+        }
+    }
+
+    /// Draws every point of `bounds`, the top left corner of the frame being `bounds.min`.
+    pub fn from_fn<C: Coord>(bounds: Bounds<C>, mut pixel: impl FnMut(Point<C>) -> Pixel) -> Self {
+        Self {
+            width: bounds.width(),
+            height: bounds.height(),
+            pixels: bounds.points().map(&mut pixel).collect(),
+        }
+    }
+
+    /// Draws the bounds of `cells`, like [`Cells::render`] but with colors.
+    pub fn of<T, G: Cells<T>>(
+        cells: &G,
+        pixel: impl Fn(Point<G::Coord>, Option<&T>) -> Pixel,
+    ) -> Self {
+        match cells.bounds() {
+            Some(bounds) => Self::from_fn(bounds, |point| pixel(point, cells.get(point))),
+            None => Self::new(0, 0),
+        }
+    }
+
+    /// Colors text drawn by a `Display` implementation character by character. Shorter lines are
+    /// padded with blank pixels.
+    pub fn from_text(text: &str, color: impl Fn(char) -> Color) -> Self {
+        let lines: Vec<&str> = text.lines().collect();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let mut frame = Self::new(width, lines.len());
+        for (y, line) in lines.into_iter().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                frame.set(Point::new(x, y), Pixel::new(ch, color(ch)));
+            }
+        }
+
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&Pixel> {
+        self.index(point).map(|index| &self.pixels[index])
+    }
+
+    /// Sets the pixel at `point`, unless it is outside of the frame.
+    pub fn set(&mut self, point: Point, pixel: Pixel) {
+        if let Some(index) = self.index(point) {
+            self.pixels[index] = pixel;
+        }
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        (point.x < self.width && point.y < self.height).then(|| point.y * self.width + point.x)
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Pixel]> {
+        // `chunks` panics on 0, and a frame without width has no rows to show anyway
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    /// The frame as text with ANSI escapes for the colors of 24 bit terminals.
+    pub fn colored(&self) -> String {
+        let mut res = String::with_capacity(self.pixels.len() * 2);
+
+        for row in self.rows() {
+            let mut current = None;
+
+            for pixel in row {
+                if current != Some(pixel.color) {
+                    let Color { r, g, b } = pixel.color;
+                    res += &format!("\x1b[38;2;{r};{g};{b}m");
+                    current = Some(pixel.color);
+                }
+
+                res.push(pixel.ch);
+            }
+
+            res += "\x1b[0m\n";
+        }
+
+        res
+    }
+}
+
+impl Display for Frame {
+    /// Writes the characters of the frame without colors, a line per row.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(|pixel| pixel.ch).collect();
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// State of a simulation that can be drawn.
+pub trait Render {
+    fn frame(&self) -> Frame;
+}
+
+/// Collects the frames a simulation emits. Only every `every`th frame is kept, and once `limit`
+/// frames are kept, every other one is dropped and twice as many are skipped from then on, so
+/// long simulations are thinned out evenly instead of running out of memory.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    every: usize,
+    limit: usize,
+    // frames emitted so far, kept or not
+    emitted: usize,
+    last_kept: bool,
+}
+
+impl Recorder {
+    pub fn new(every: usize, limit: usize) -> Self {
+        Self {
+            frames: Vec::new(),
+            every: every.max(1),
+            limit: limit.max(2),
+            emitted: 0,
+            last_kept: false,
+        }
+    }
+
+    /// Emits the current state of a simulation.
+    pub fn record(&mut self, state: &impl Render) {
+        self.record_with(|| state.frame());
+    }
+
+    /// Emits a frame, which is only drawn if it is kept.
+    pub fn record_with(&mut self, frame: impl FnOnce() -> Frame) {
+        let index = self.emitted;
+        self.emitted += 1;
+        self.last_kept = index.is_multiple_of(self.every);
+
+        if !self.last_kept {
+            return;
+        }
+
+        if self.frames.len() == self.limit {
+            self.frames = std::mem::take(&mut self.frames)
+                .into_iter()
+                .step_by(2)
+                .collect();
+            self.every *= 2;
+
+            // the kept frames are now those at multiples of the new step
+            if !index.is_multiple_of(self.every) {
+                self.last_kept = false;
+                return;
+            }
+        }
+
+        self.frames.push(frame());
+    }
+
+    /// Emits the final state, which is kept even if it would be skipped.
+    pub fn finish(&mut self, state: &impl Render) {
+        self.finish_with(|| state.frame());
+    }
+
+    /// Like [`Recorder::finish`], with the frame drawn only if it was not kept already.
+    pub fn finish_with(&mut self, frame: impl FnOnce() -> Frame) {
+        if !self.last_kept {
+            self.frames.push(frame());
+            self.last_kept = true;
+        }
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// The kept frames, shown `delay` apart.
+    pub fn into_animation(self, delay: Duration) -> Animation {
+        Animation {
+            frames: self.frames,
+            delay,
+        }
+    }
+}
+
+/// Recorded frames, to be shown one after the other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    pub frames: Vec<Frame>,
+    pub delay: Duration,
+}
+
+impl Animation {
+    /// Shows every frame in color on a cleared terminal, waiting the delay between frames.
+    pub fn play(&self, out: &mut impl Write) -> Result<()> {
+        for (idx, frame) in self.frames.iter().enumerate() {
+            if idx > 0 {
+                thread::sleep(self.delay);
+            }
+
+            // clear the screen and move to its top left corner
+            write!(out, "\x1b[2J\x1b[H{}", frame.colored())?;
+            out.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the animation to `path`, in a format chosen by its extension: an animated `gif`, a
+    /// `png` of the last frame or `txt` with every frame followed by an empty line. In images
+    /// each cell is a square of `scale` pixels.
+    pub fn save(&self, path: &Path, scale: usize) -> Result<()> {
+        ensure!(!self.frames.is_empty(), "There are no frames to save");
+        ensure!(scale > 0, "The scale needs to be at least one pixel");
+
+        // encoded in memory first, so nothing is left behind if the format is not supported
+        let mut out = Vec::new();
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("gif") => self.write_gif(&mut out, scale)?,
+            Some("png") => self.write_png(&mut out, scale)?,
+            Some("txt") => self.write_text(&mut out)?,
+            _ => bail!(
+                "Unsupported file {}, expected a .gif, .png or .txt",
+                path.display()
+            ),
+        }
+
+        fs::write(path, out).with_context(|| format!("Could not write file {}", path.display()))
+    }
+
+    fn write_text(&self, out: &mut impl Write) -> Result<()> {
+        for frame in &self.frames {
+            writeln!(out, "{frame}")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_png(&self, out: &mut impl Write, scale: usize) -> Result<()> {
+        let frame = &self.frames[self.frames.len() - 1];
+        let (width, height) = (frame.width * scale, frame.height * scale);
+
+        let mut data = Vec::with_capacity(width * height * 3);
+        for color in scaled(frame, width, height, scale) {
+            data.extend([color.r, color.g, color.b]);
+        }
+
+        let mut encoder = png::Encoder::new(out, u32::try_from(width)?, u32::try_from(height)?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&data)?;
+
+        Ok(writer.finish()?)
+    }
+
+    /// Every frame is drawn on a canvas the size of the largest one, aligned to the top left.
+    fn write_gif(&self, out: &mut impl Write, scale: usize) -> Result<()> {
+        let width = self
+            .frames
+            .iter()
+            .map(Frame::width)
+            .max()
+            .unwrap_or_default()
+            * scale;
+        let height = self
+            .frames
+            .iter()
+            .map(Frame::height)
+            .max()
+            .unwrap_or_default()
+            * scale;
+        let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+            bail!("The frames are too large for a GIF at {width}x{height} pixels");
+        };
+
+        // days use a handful of colors, so they share a global palette without quantizing
+        let mut palette: HashMap<Color, u8> = HashMap::from([(Color::BLACK, 0)]);
+        let mut colors = vec![Color::BLACK];
+
+        for pixel in self.frames.iter().flat_map(|frame| &frame.pixels) {
+            if let Entry::Vacant(entry) = palette.entry(pixel.color) {
+                let Ok(index) = u8::try_from(colors.len()) else {
+                    bail!("The frames use more than 256 colors");
+                };
+
+                entry.insert(index);
+                colors.push(pixel.color);
+            }
+        }
+
+        let palette_bytes: Vec<u8> = colors
+            .iter()
+            .flat_map(|color| [color.r, color.g, color.b])
+            .collect();
+
+        let mut encoder = gif::Encoder::new(out, gif_width, gif_height, &palette_bytes)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        // in hundredths of a second
+        let delay = u16::try_from(self.delay.as_millis() / 10).unwrap_or(u16::MAX);
+
+        for frame in &self.frames {
+            let pixels: Vec<u8> = scaled(frame, width, height, scale)
+                .map(|color| palette[&color])
+                .collect();
+
+            let mut frame = gif::Frame::from_indexed_pixels(gif_width, gif_height, pixels, None);
+            frame.delay = delay;
+
+            encoder.write_frame(&frame)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Colors of a `width` by `height` image of `frame`, row by row, with each cell drawn as a square
+/// of `scale` pixels. Pixels past the frame are black.
+fn scaled(
+    frame: &Frame,
+    width: usize,
+    height: usize,
+    scale: usize,
+) -> impl Iterator<Item = Color> + '_ {
+    (0..height).flat_map(move |y| {
+        (0..width).map(move |x| {
+            frame
+                .get(Point::new(x / scale, y / scale))
+                .map_or(Color::BLACK, |pixel| pixel.color)
+        })
+    })
+}
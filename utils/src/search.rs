@@ -0,0 +1,294 @@
+//! Shortest path searches over implicit graphs. Nodes are only discovered through a neighbour
+//! function, so the graph never has to be built up front.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Weight of an edge. `Default` has to be zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// A shortest path found by one of the searches, including both ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C = usize> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn end(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+
+    /// Number of edges taken.
+    pub fn steps(&self) -> usize {
+        self.nodes.len() - 1
+    }
+}
+
+/// Finds the path with the fewest steps from `start` to the first node satisfying `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], neighbours, is_goal)
+}
+
+/// Like [`bfs`], but starting from all of `starts` at once, which finds the shortest path from
+/// whichever start is closest to a goal.
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = parents.entry(start.clone()) {
+            entry.insert(None);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_goal(&node) {
+            let nodes = reconstruct(&parents, node);
+            return Some(Path { nodes, cost });
+        }
+
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest path from `start` to the first node satisfying `is_goal`. `neighbours`
+/// returns each neighbour together with the cost of the edge leading to it.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but guided by `heuristic`, an estimate of the remaining cost to a goal. The
+/// path is only guaranteed to be the cheapest if the estimate never exceeds the actual cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = BinaryHeap::from([Queued {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if costs[&node] < cost {
+            // found a cheaper way since this was queued
+            continue;
+        }
+
+        if is_goal(&node) {
+            let nodes = reconstruct(&parents, node);
+            return Some(Path { nodes, cost });
+        }
+
+        for (next, step) in neighbours(&node) {
+            let cost = cost + step;
+
+            if costs.get(&next).is_some_and(|&known| known <= cost) {
+                continue;
+            }
+
+            costs.insert(next.clone(), cost);
+            parents.insert(next.clone(), Some(node.clone()));
+            queue.push(Queued {
+                estimate: cost + heuristic(&next),
+                cost,
+                node: next,
+            });
+        }
+    }
+
+    None
+}
+
+/// Entry of the priority queue, ordered so that the [`BinaryHeap`] pops the lowest estimate
+/// first.
+struct Queued<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+/// Follows the parents from `end` back to a start, i.e. a node without a parent.
+fn reconstruct<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, end: N) -> Vec<N> {
+    let mut nodes = vec![end];
+
+    while let Some(Some(parent)) = parents.get(&nodes[nodes.len() - 1]) {
+        nodes.push(parent.clone());
+    }
+
+    nodes.reverse();
+    nodes
+}
+
+/// Shortest paths between every pair of nodes, as computed by [`floyd_warshall`].
+#[derive(Debug, Clone)]
+pub struct AllPairs<N, C = usize> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    costs: Vec<Vec<Option<C>>>,
+    // successor of the first node on the shortest path to the second one
+    next: Vec<Vec<Option<usize>>>,
+}
+
+/// Computes the shortest paths between all pairs of `nodes`. Edges to nodes that are not part
+/// of `nodes` are ignored.
+pub fn floyd_warshall<N, C, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> AllPairs<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let nodes: Vec<N> = nodes.into_iter().collect();
+    let index: HashMap<N, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(idx, node)| (node.clone(), idx))
+        .collect();
+
+    let len = nodes.len();
+    let mut costs = vec![vec![None; len]; len];
+    let mut next = vec![vec![None; len]; len];
+
+    for (from, node) in nodes.iter().enumerate() {
+        costs[from][from] = Some(C::default());
+        next[from][from] = Some(from);
+
+        for (neighbour, cost) in neighbours(node) {
+            let Some(&to) = index.get(&neighbour) else {
+                continue;
+            };
+
+            if costs[from][to].is_none_or(|known| cost < known) {
+                costs[from][to] = Some(cost);
+                next[from][to] = Some(to);
+            }
+        }
+    }
+
+    for via in 0..len {
+        for from in 0..len {
+            let Some(first) = costs[from][via] else {
+                continue;
+            };
+
+            for to in 0..len {
+                let Some(second) = costs[via][to] else {
+                    continue;
+                };
+
+                if costs[from][to].is_none_or(|known| first + second < known) {
+                    costs[from][to] = Some(first + second);
+                    next[from][to] = next[from][via];
+                }
+            }
+        }
+    }
+
+    AllPairs {
+        nodes,
+        index,
+        costs,
+        next,
+    }
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> AllPairs<N, C> {
+    /// The nodes in the order they were passed in.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Cost of the cheapest path, `None` if there is none or either node is unknown.
+    pub fn cost(&self, from: &N, to: &N) -> Option<C> {
+        self.costs[*self.index.get(from)?][*self.index.get(to)?]
+    }
+
+    pub fn path(&self, from: &N, to: &N) -> Option<Path<N, C>> {
+        let (mut at, to) = (*self.index.get(from)?, *self.index.get(to)?);
+        let cost = self.costs[at][to]?;
+
+        let mut nodes = vec![self.nodes[at].clone()];
+        while at != to {
+            at = self.next[at][to]?;
+            nodes.push(self.nodes[at].clone());
+        }
+
+        Some(Path { nodes, cost })
+    }
+}
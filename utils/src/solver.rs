@@ -0,0 +1,182 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    bench, init_tracing, trace, write_benches, Args, Bench, Printer, RawParams, Record, Recorder,
+};
+
+/// A single day's puzzle, split into stages so they can be run and timed independently: the input
+/// is parsed once, and both parts are computed from the parsed representation.
+pub trait Solver {
+    /// Day of the advent calendar this solver belongs to.
+    const DAY: u8;
+
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Constants the puzzle gives outside of the input, like the row asked about on day 15, which
+    /// differ between the examples and the real input. The default holds the real values, `()`
+    /// means there are none.
+    type Params: Default + DeserializeOwned;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed, params: &Self::Params) -> Result<Self::Answer1>;
+
+    fn part2(parsed: &Self::Parsed, params: &Self::Params) -> Result<Self::Answer2>;
+
+    /// Replays how the answers are found as frames emitted into `recorder`, for days that have
+    /// something to show.
+    fn animate(_: &Self::Parsed, _: &Self::Params, _: &mut Recorder) -> Result<()> {
+        bail!("Day {} has no animation", Self::DAY)
+    }
+}
+
+/// Runs `f` and measures how long it took.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let time = Instant::now();
+    let res = f();
+
+    (res, time.elapsed())
+}
+
+/// Time spent in each stage of a [`Solver`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Answers for both parts of a day. Parts fail independently, so one missing answer does not hide
+/// the other.
+#[derive(Debug)]
+pub struct Answers {
+    pub part1: Result<String>,
+    pub part2: Result<String>,
+    pub timings: Timings,
+}
+
+/// Type-erased handle to a [`Solver`], used by the runner to keep all days in one list.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    solve: fn(&str, &RawParams) -> Result<Answers>,
+    bench: fn(&str, &RawParams, usize) -> Result<Bench>,
+    animate: fn(&str, &RawParams, &mut Recorder) -> Result<()>,
+}
+
+impl Day {
+    pub fn new<S: Solver>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+            bench: |input, params, runs| bench::<S>(input, &params.get()?, runs),
+            animate: |input, params, recorder| {
+                S::animate(&trace::parse::<S>(input)?, &params.get()?, recorder)
+            },
+        }
+    }
+
+    /// Solves `input`, with `params` converted to the [`Solver::Params`] of the day.
+    pub fn solve(&self, input: &str, params: &RawParams) -> Result<Answers> {
+        (self.solve)(input, params)
+    }
+
+    /// See [`bench`].
+    pub fn bench(&self, input: &str, params: &RawParams, runs: usize) -> Result<Bench> {
+        (self.bench)(input, params, runs)
+    }
+
+    /// See [`Solver::animate`].
+    pub fn animate(&self, input: &str, params: &RawParams, recorder: &mut Recorder) -> Result<()> {
+        (self.animate)(input, params, recorder)
+    }
+}
+
+fn solve<S: Solver>(input: &str, params: &RawParams) -> Result<Answers> {
+    let params = params.get()?;
+
+    let (parsed, parse) = timed(|| trace::parse::<S>(input));
+    let parsed = parsed?;
+
+    let (part1, part1_time) = timed(|| trace::part1::<S>(&parsed, &params));
+    let (part2, part2_time) = timed(|| trace::part2::<S>(&parsed, &params));
+
+    Ok(Answers {
+        part1: part1.map(|answer| answer.to_string()),
+        part2: part2.map(|answer| answer.to_string()),
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+/// Entry point for the per-day binaries: parses the shared command line [`Args`], reads the
+/// selected input and prints the requested parts, or benchmarks them with `--bench`.
+pub fn run<S: Solver>() -> Result<()> {
+    init_tracing();
+
+    run_with::<S>(&Args::parse())
+}
+
+/// Like [`run`], for binaries that parse [`Args`] along with options of their own.
+pub fn run_with<S: Solver>(args: &Args) -> Result<()> {
+    let (input, params) = args.load(S::DAY)?;
+    let params: S::Params = params.get()?;
+
+    if let Some(runs) = args.bench {
+        let results = bench::<S>(&input, &params, runs)?;
+        Printer::stdout(args).print_bench(&results)?;
+
+        if let Some(path) = &args.bench_output {
+            write_benches(path, &[results])?;
+        }
+
+        return Ok(());
+    }
+
+    print_answers::<S>(args, &trace::parse::<S>(&input)?, &params)
+}
+
+/// Prints the parts requested by `args` for an input the binary parsed itself. A part that fails
+/// does not keep the other one from being printed; the failures are returned together at the end.
+pub fn print_answers<S: Solver>(args: &Args, parsed: &S::Parsed, params: &S::Params) -> Result<()> {
+    let mut printer = Printer::stdout(args);
+    let mut failures = Vec::new();
+
+    if args.part.includes(1) {
+        let (answer, elapsed) = timed(|| trace::part1::<S>(parsed, params));
+        match answer {
+            Ok(answer) => printer.print(&Record::new(S::DAY, 1, answer, elapsed))?,
+            Err(err) => failures.push(format!("Part 1 failed: {err:#}")),
+        }
+    }
+
+    if args.part.includes(2) {
+        let (answer, elapsed) = timed(|| trace::part2::<S>(parsed, params));
+        match answer {
+            Ok(answer) => printer.print(&Record::new(S::DAY, 2, answer, elapsed))?,
+            Err(err) => failures.push(format!("Part 2 failed: {err:#}")),
+        }
+    }
+
+    ensure!(failures.is_empty(), "{}", failures.join("\n"));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! Instrumentation of the solvers with [`tracing`]. Stages run in `parse` and `part` spans, and
+//! days emit events as their simulations go: `debug` for the outcome of a stage and `trace` for
+//! every step.
+//!
+//! Nothing is printed unless `RUST_LOG` is set, with the usual filter syntax like
+//! `RUST_LOG=day_14=trace` or `RUST_LOG=debug,day_16=off`. Release builds leave out the `trace`
+//! events altogether, so the steps cost nothing there and are only seen in debug builds.
+
+use std::{env, fmt::Display};
+
+use anyhow::Result;
+use tracing::{debug, info_span};
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+use crate::Solver;
+
+/// Variable holding the filter of the events to print.
+pub const LOG_VAR: &str = "RUST_LOG";
+
+/// Prints events matching the filter in [`LOG_VAR`] to stderr, along with the time spent in each
+/// span. Does nothing if the variable is not set, or if events are already collected.
+pub fn init_tracing() {
+    if env::var_os(LOG_VAR).is_none() {
+        return;
+    }
+
+    // invalid directives of the filter are skipped rather than keeping the answers from being
+    // printed
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .try_init();
+}
+
+/// [`Solver::parse`] in a `parse` span.
+pub(crate) fn parse<S: Solver>(input: &str) -> Result<S::Parsed> {
+    let _span = info_span!("parse", day = S::DAY).entered();
+
+    S::parse(input).inspect_err(|err| debug!("failed: {err:#}"))
+}
+
+/// [`Solver::part1`] in a `part` span.
+pub(crate) fn part1<S: Solver>(parsed: &S::Parsed, params: &S::Params) -> Result<S::Answer1> {
+    let _span = info_span!("part", day = S::DAY, part = 1).entered();
+
+    traced(S::part1(parsed, params))
+}
+
+/// [`Solver::part2`] in a `part` span.
+pub(crate) fn part2<S: Solver>(parsed: &S::Parsed, params: &S::Params) -> Result<S::Answer2> {
+    let _span = info_span!("part", day = S::DAY, part = 2).entered();
+
+    traced(S::part2(parsed, params))
+}
+
+fn traced<T: Display>(answer: Result<T>) -> Result<T> {
+    match &answer {
+        Ok(answer) => debug!(%answer, "solved"),
+        Err(err) => debug!("failed: {err:#}"),
+    }
+
+    answer
+}
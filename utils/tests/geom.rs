@@ -0,0 +1,108 @@
+use utils::{Bounds, Direction, Direction8, Point};
+
+#[test]
+fn steps_stop_at_the_edges_of_the_coordinate_type() {
+    let origin: Point<usize> = Point::new(0, 0);
+    assert_eq!(origin.step(Direction::Up), None);
+    assert_eq!(origin.step(Direction::Left), None);
+    assert_eq!(origin.step(Direction::Right), Some(Point::new(1, 0)));
+    assert_eq!(origin.step(Direction8::DownRight), Some(Point::new(1, 1)));
+    assert_eq!(origin.neighbours4().count(), 2);
+    assert_eq!(origin.neighbours8().count(), 3);
+
+    let corner: Point<i32> = Point::new(i32::MAX, i32::MIN);
+    assert_eq!(corner.step(Direction::Right), None);
+    assert_eq!(corner.step(Direction::Up), None);
+    assert_eq!(corner.neighbours8().count(), 3);
+
+    // `y` grows downwards
+    assert_eq!(Point::new(5, 5).step(Direction::Up), Some(Point::new(5, 4)));
+    assert_eq!(Point::new(5, 5).offset((-2, 3)), Some(Point::new(3, 8)));
+}
+
+#[test]
+fn coordinates_above_i64_are_valid() {
+    let far: Point<u64> = Point::new(u64::MAX, u64::MAX - 1);
+    assert_eq!(far.step(Direction::Right), None);
+    assert_eq!(
+        far.step(Direction::Down),
+        Some(Point::new(u64::MAX, u64::MAX))
+    );
+    assert_eq!(far.manhattan(Point::new(u64::MAX - 3, u64::MAX)), 4);
+
+    // more steps than fit into usize
+    let origin = Point::new(0, 0);
+    assert_eq!(far.manhattan(origin), usize::MAX);
+    assert_eq!(far.chebyshev(origin), usize::MAX);
+
+    let bounds = Bounds::new(origin, far);
+    assert!(bounds.contains(Point::new(u64::MAX, 0)));
+    assert_eq!(bounds.width(), usize::MAX);
+    assert_eq!(bounds.height(), usize::MAX);
+}
+
+#[test]
+fn distances_and_signs() {
+    let a: Point<i64> = Point::new(1, -2);
+    let b = Point::new(-3, 5);
+
+    assert_eq!(a.manhattan(b), 11);
+    assert_eq!(a.chebyshev(b), 7);
+    assert_eq!(a.manhattan(a), 0);
+
+    assert_eq!((b - a).signum(), Point::new(-1, 1));
+    assert_eq!(Point::new(0, 7).signum(), Point::new(0, 1));
+    assert_eq!(Point::<usize>::new(0, 9).signum(), Point::new(0, 1));
+}
+
+#[test]
+fn directions_turn_around() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+    }
+
+    for direction in Direction8::ALL {
+        let (dx, dy) = direction.step();
+        let (ox, oy) = direction.opposite().step();
+        assert_eq!((dx + ox, dy + oy), (0, 0));
+        assert_eq!(direction.is_diagonal(), dx != 0 && dy != 0);
+    }
+
+    assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+}
+
+#[test]
+fn bounds_grow_around_points() {
+    let points = [Point::new(2, 3), Point::new(-1, 4), Point::new(0, -2)];
+    let bounds: Bounds<i32> = Bounds::of_points(points).unwrap();
+
+    assert_eq!(
+        bounds.corners(),
+        [
+            Point::new(-1, -2),
+            Point::new(2, -2),
+            Point::new(2, 4),
+            Point::new(-1, 4)
+        ]
+    );
+    assert_eq!((bounds.width(), bounds.height()), (4, 7));
+    assert_eq!(bounds.points().count(), 28);
+    assert!(points.iter().all(|&point| bounds.contains(point)));
+
+    let other = Bounds::new(Point::new(1, 1), Point::new(5, 5));
+    assert_eq!(
+        bounds.intersection(other),
+        Some(Bounds::new(Point::new(1, 1), Point::new(2, 4)))
+    );
+    assert_eq!(bounds.union(other).max, Point::new(5, 5));
+    assert_eq!(
+        bounds.intersection(Bounds::of_point(Point::new(9, 9))),
+        None
+    );
+    assert_eq!(Bounds::<i32>::of_points([]), None);
+
+    let single = Bounds::of_point(Point::new(3usize, 3));
+    assert_eq!(single.points().collect::<Vec<_>>(), [Point::new(3, 3)]);
+}
@@ -0,0 +1,108 @@
+use utils::{Bounds, Cells, Cursor, Grid, Point, SparseGrid};
+
+const MAP: &str = "ab.\n.cd\n";
+
+fn grid() -> Grid<char> {
+    Grid::parse_with(&mut Cursor::new(MAP), |cursor| cursor.char()).unwrap()
+}
+
+fn points<T>(cells: impl Iterator<Item = (Point<usize>, T)>) -> Vec<(usize, usize)> {
+    cells.map(|(point, _)| (point.x, point.y)).collect()
+}
+
+#[test]
+fn cells_outside_of_the_grid_are_missing() {
+    let grid = grid();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+
+    assert_eq!(grid.get(Point::new(2, 1)), Some(&'d'));
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, 2)), None);
+    assert_eq!(grid[Point::new(1, 0)], 'b');
+
+    // a grid that does not start at the origin
+    let shifted = Grid::new(Bounds::new(Point::new(-2, -2), Point::new(-1, -1)), 0);
+    assert_eq!(shifted.get(Point::new(-2, -1)), Some(&0));
+    assert_eq!(shifted.get(Point::new(0, 0)), None);
+
+    let ragged = Grid::<char>::parse_with(&mut Cursor::new("ab\nc\n"), |cursor| cursor.char());
+    assert!(ragged.is_err());
+    assert!(Grid::<char>::from_rows(Point::new(0, 0), vec![vec![]]).is_none());
+}
+
+#[test]
+fn neighbours_stop_at_the_edges() {
+    let grid = grid();
+
+    assert_eq!(points(grid.neighbours4(Point::new(0, 0))), [(1, 0), (0, 1)]);
+    assert_eq!(points(grid.neighbours8(Point::new(0, 0))).len(), 3);
+    assert_eq!(points(grid.neighbours8(Point::new(1, 1))).len(), 5);
+    assert_eq!(points(grid.neighbours4(Point::new(2, 1))), [(2, 0), (1, 1)]);
+
+    let ray: String = grid
+        .ray(Point::new(0, 1), utils::Direction::Right)
+        .map(|(_, &cell)| cell)
+        .collect();
+    assert_eq!(ray, "cd");
+}
+
+#[test]
+fn rows_and_columns_are_read_in_order() {
+    let grid = grid();
+
+    let row: String = grid.row(1).map(|(_, &cell)| cell).collect();
+    assert_eq!(row, ".cd");
+    let column: String = grid.column(2).map(|(_, &cell)| cell).collect();
+    assert_eq!(column, ".d");
+    assert_eq!(grid.row(5).count(), 0);
+
+    let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    assert_eq!(rows, ["ab.", ".cd"]);
+
+    let mut sparse: SparseGrid<char, usize> = grid
+        .iter()
+        .filter(|(_, &cell)| cell != '.')
+        .map(|(point, &cell)| (point, cell))
+        .collect();
+    assert_eq!(points(sparse.row(1)), [(1, 1), (2, 1)]);
+    assert_eq!(points(sparse.column(0)), [(0, 0)]);
+
+    sparse.remove(Point::new(1, 1));
+    assert_eq!(points(sparse.row(1)), [(2, 1)]);
+}
+
+#[test]
+fn empty_sparse_grids_have_no_bounds() {
+    let mut sparse: SparseGrid<char> = SparseGrid::new();
+    assert_eq!(sparse.bounds(), None);
+    assert_eq!(sparse.render(|_, _| '#'), "");
+    assert_eq!(sparse.row(0).count(), 0);
+
+    sparse.insert(Point::new(-1, 2), '#');
+    sparse.insert(Point::new(1, 0), '#');
+    assert_eq!(
+        sparse.bounds(),
+        Some(Bounds::new(Point::new(-1, 0), Point::new(1, 2)))
+    );
+
+    // the bounds never shrink
+    sparse.remove(Point::new(1, 0));
+    assert_eq!(sparse.len(), 1);
+    assert_eq!(sparse.bounds().unwrap().max, Point::new(1, 2));
+}
+
+#[test]
+fn grids_render_their_bounds() {
+    let grid = grid();
+    assert_eq!(grid.render(|_, cell| *cell.unwrap()), MAP.trim_end());
+    assert_eq!(
+        grid.map(|&cell| cell != '.')
+            .render(|_, cell| if *cell.unwrap() { '#' } else { ' ' }),
+        "## \n ##"
+    );
+
+    let sparse: SparseGrid<char> = [(Point::new(0, 0), 'x'), (Point::new(2, 1), 'y')]
+        .into_iter()
+        .collect();
+    assert_eq!(sparse.render(|_, cell| *cell.unwrap_or(&'.')), "x..\n..y");
+}
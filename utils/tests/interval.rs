@@ -0,0 +1,102 @@
+use std::ops::RangeInclusive;
+
+use utils::IntervalSet;
+
+fn ranges<T: utils::Coord>(set: &IntervalSet<T>) -> Vec<RangeInclusive<T>> {
+    set.ranges().collect()
+}
+
+#[test]
+fn overlapping_and_adjacent_ranges_are_merged() {
+    let mut set: IntervalSet<i32> = [1..=3, 7..=9].into_iter().collect();
+    assert_eq!(ranges(&set), [1..=3, 7..=9]);
+
+    set.insert(4..=4);
+    assert_eq!(ranges(&set), [1..=4, 7..=9]);
+    set.insert(5..=6);
+    assert_eq!(ranges(&set), [1..=9]);
+    assert_eq!(set.len(), 9);
+
+    set.insert(-5..=-1);
+    set.insert(0..=0);
+    #[allow(clippy::reversed_empty_ranges)]
+    set.insert(12..=11);
+    assert_eq!(ranges(&set), [-5..=9]);
+
+    // the same set, however it was built
+    assert_eq!(set, IntervalSet::from(-5..=9));
+}
+
+#[test]
+fn removing_the_middle_of_a_range_splits_it() {
+    let mut set = IntervalSet::from(1..=10);
+    set.remove(4..=6);
+    assert_eq!(ranges(&set), [1..=3, 7..=10]);
+    assert!(!set.contains(5));
+    assert!(set.contains(7));
+
+    set.remove(1..=1);
+    set.remove(10..=20);
+    #[allow(clippy::reversed_empty_ranges)]
+    set.remove(6..=5);
+    assert_eq!(ranges(&set), [2..=3, 7..=9]);
+
+    set.remove(0..=100);
+    assert!(set.is_empty());
+
+    let mut full = IntervalSet::from(i64::MIN..=i64::MAX);
+    // one more value than fits into usize
+    assert_eq!(full.len(), usize::MAX);
+    full.remove(0..=0);
+    assert_eq!(ranges(&full), [i64::MIN..=-1, 1..=i64::MAX]);
+    assert_eq!(full.len(), usize::MAX);
+    full.remove(1..=1);
+    assert_eq!(full.len(), usize::MAX - 1);
+}
+
+#[test]
+fn intersection_union_and_difference() {
+    let a: IntervalSet<i32> = [0..=5, 10..=15, 20..=25].into_iter().collect();
+    let b: IntervalSet<i32> = [3..=12, 15..=21].into_iter().collect();
+
+    assert_eq!(
+        ranges(&a.intersection(&b)),
+        [3..=5, 10..=12, 15..=15, 20..=21]
+    );
+    assert_eq!(a.intersection(&b), b.intersection(&a));
+    assert_eq!(ranges(&a.union(&b)), [0..=25]);
+    assert_eq!(ranges(&a.difference(&b)), [0..=2, 13..=14, 22..=25]);
+
+    assert!(a.overlaps(&b));
+    assert!(!a.overlaps(&IntervalSet::from(6..=9)));
+    assert!(a.union(&b).is_superset(&a));
+    assert!(!a.is_superset(&b));
+    assert!(a.contains_range(&(11..=14)));
+    assert!(!a.contains_range(&(4..=10)));
+}
+
+#[test]
+fn gaps_lie_between_the_ranges() {
+    let set: IntervalSet<i32> = [-3..=-1, 2..=2, 8..=9].into_iter().collect();
+    assert_eq!(set.gaps().collect::<Vec<_>>(), [0..=1, 3..=7]);
+
+    assert_eq!(IntervalSet::from(1..=5).gaps().count(), 0);
+    assert_eq!(IntervalSet::<i32>::new().gaps().count(), 0);
+}
+
+#[test]
+fn unsigned_ranges_can_start_at_zero() {
+    let mut set: IntervalSet<usize> = [0..=0, 1..=4].into_iter().collect();
+    assert_eq!(ranges(&set), [0..=4]);
+
+    set.remove(0..=0);
+    assert_eq!(ranges(&set), [1..=4]);
+    set.insert(0..=0);
+    set.remove(1..=2);
+    assert_eq!(ranges(&set), [0..=0, 3..=4]);
+    assert_eq!(set.gaps().collect::<Vec<_>>(), [1..=2]);
+    assert_eq!(set.len(), 3);
+
+    set.remove(0..=3);
+    assert_eq!(ranges(&set), [4..=4]);
+}
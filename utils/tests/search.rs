@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use utils::search::{self, Path};
+
+/// A shortcut that is one edge but costs more than the long way round.
+fn weighted(node: &char) -> Vec<(char, usize)> {
+    match node {
+        'a' => vec![('b', 10), ('c', 1)],
+        'c' => vec![('d', 1)],
+        'd' => vec![('b', 1), ('e', 5)],
+        'b' => vec![('e', 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn dijkstra_takes_the_cheapest_path_where_bfs_takes_the_shortest() {
+    let unweighted = |node: &char| weighted(node).into_iter().map(|(next, _)| next);
+
+    let bfs = search::bfs('a', unweighted, |&node| node == 'b').unwrap();
+    assert_eq!(bfs.nodes, ['a', 'b']);
+    assert_eq!(bfs.steps(), 1);
+
+    let dijkstra = search::dijkstra('a', weighted, |&node| node == 'b').unwrap();
+    assert_eq!(
+        dijkstra,
+        Path {
+            nodes: vec!['a', 'c', 'd', 'b'],
+            cost: 3
+        }
+    );
+
+    let dijkstra = search::dijkstra('a', weighted, |&node| node == 'e').unwrap();
+    assert_eq!(dijkstra.nodes, ['a', 'c', 'd', 'b', 'e']);
+    assert_eq!(dijkstra.cost, 4);
+
+    assert_eq!(search::dijkstra('b', weighted, |&node| node == 'a'), None);
+}
+
+#[test]
+fn astar_finds_the_cheapest_path_with_an_admissible_heuristic() {
+    // a wall at x = 2 with a single opening at y = 4, and expensive cells at y = 4 left of it
+    let walls: HashSet<(i32, i32)> = (0..4).map(|y| (2, y)).collect();
+    let neighbours = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+            .filter(|next| !walls.contains(next))
+            .map(|next| (next, if next.1 == 4 && next.0 < 2 { 3u32 } else { 1 }))
+            .collect::<Vec<_>>()
+    };
+    let goal = (4, 0);
+    // every step costs at least 1, so the manhattan distance never overestimates
+    let manhattan =
+        |&(x, y): &(i32, i32)| (goal.0 - x).unsigned_abs() + (goal.1 - y).unsigned_abs();
+
+    let astar = search::astar((0, 0), neighbours, manhattan, |&node| node == goal).unwrap();
+    let dijkstra = search::dijkstra((0, 0), neighbours, |&node| node == goal).unwrap();
+
+    assert_eq!(astar.cost, dijkstra.cost);
+    // down to (1, 3), through the opening for 3 + 1, and then 6 steps to the goal
+    assert_eq!(astar.cost, 14);
+    assert_eq!((astar.start(), astar.end()), (&(0, 0), &goal));
+    assert!(astar.nodes.contains(&(2, 4)));
+}
+
+#[test]
+fn bfs_reconstructs_the_path_from_the_closest_start() {
+    let neighbours = |&node: &u32| [node + 1, node * 2];
+
+    let path = search::bfs(1, neighbours, |&node| node == 10).unwrap();
+    assert_eq!(path.nodes, [1, 2, 4, 5, 10]);
+    assert_eq!(path.cost, 4);
+
+    let path = search::bfs_multi([1, 9], neighbours, |&node| node == 10).unwrap();
+    assert_eq!(path.nodes, [9, 10]);
+
+    let path = search::bfs(10, neighbours, |&node| node == 10).unwrap();
+    assert_eq!((path.steps(), path.nodes), (0, vec![10]));
+}
+
+#[test]
+fn floyd_warshall_reconstructs_every_path() {
+    let edges = |node: &char| match node {
+        'a' => vec![('b', 4), ('c', 1)],
+        'c' => vec![('b', 2), ('z', 1)],
+        'b' => vec![('d', 1)],
+        _ => vec![],
+    };
+    let all_pairs = search::floyd_warshall(['a', 'b', 'c', 'd', 'e'], edges);
+
+    assert_eq!(
+        all_pairs.path(&'a', &'d'),
+        Some(Path {
+            nodes: vec!['a', 'c', 'b', 'd'],
+            cost: 4usize
+        })
+    );
+    assert_eq!(all_pairs.path(&'c', &'d').unwrap().nodes, ['c', 'b', 'd']);
+    assert_eq!(all_pairs.path(&'b', &'b').unwrap().nodes, ['b']);
+    assert_eq!(all_pairs.cost(&'b', &'b'), Some(0));
+
+    // no way back, isolated and unknown nodes
+    assert_eq!(all_pairs.path(&'d', &'a'), None);
+    assert_eq!(all_pairs.path(&'a', &'e'), None);
+    assert_eq!(all_pairs.cost(&'c', &'z'), None);
+}